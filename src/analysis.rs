@@ -2,8 +2,10 @@
 // SPDX-License-Identifier: MIT
 
 pub mod ast_visitor;
+pub mod explain;
 pub mod extractor;
 pub mod mapper;
+pub mod suppression;
 
 pub use extractor::extract_semantic_units;
 pub use mapper::{MapResult, map_changes};
@@ -3,11 +3,12 @@
 
 use proc_macro2::{Span, TokenStream, TokenTree};
 use syn::{
-    Attribute, File, ImplItem, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemMacro, ItemMod,
+    Attribute, File, ImplItem, Item, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemMacro, ItemMod,
     ItemStatic, ItemStruct, ItemTrait, ItemType, TraitItem, Visibility as SynVisibility,
     spanned::Spanned, visit::Visit,
 };
 
+use super::suppression::ATTRIBUTE_MARKER;
 use crate::types::{LineSpan, SemanticUnit, SemanticUnitKind, Visibility};
 
 /// Checks whether a `cfg` predicate token stream enables the item for test
@@ -76,10 +77,66 @@ fn collect_cfg_features(tokens: TokenStream, inside_not: bool, out: &mut Vec<Str
     }
 }
 
+/// Checks whether an item is an `include!` of a file below `OUT_DIR`, as
+/// emitted for prost, tonic or bindgen output:
+/// `include!(concat!(env!("OUT_DIR"), "/api.rs"));`
+fn is_out_dir_include(item: &Item) -> bool {
+    match item {
+        Item::Macro(item) => is_out_dir_include_macro(item),
+        _ => false,
+    }
+}
+
+/// Checks whether an item macro includes a file below `OUT_DIR`, either
+/// through `include!` or through tonic's `include_proto!`, which always
+/// reads from `OUT_DIR`
+fn is_out_dir_include_macro(item: &ItemMacro) -> bool {
+    if item.mac.path.is_ident("include") {
+        return item.mac.tokens.to_string().contains("OUT_DIR");
+    }
+    item.mac
+        .path
+        .segments
+        .last()
+        .is_some_and(|s| s.ident == "include_proto")
+}
+
+/// Checks whether an attribute opts the item out of analysis
+///
+/// Accepts the inert `#[cfg_attr(any(), rust_diff_analyzer::skip)]` form,
+/// which compiles without any helper crate, and the bare
+/// `#[rust_diff_analyzer::skip]` path.
+fn is_skip_attribute(attr: &Attribute) -> bool {
+    const SKIP_PATH: &str = "rust_diff_analyzer::skip";
+
+    let path = attr.path();
+    if path.is_ident("cfg_attr") {
+        return attr.meta.require_list().is_ok_and(|meta| {
+            let tokens: String = meta
+                .tokens
+                .to_string()
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect();
+            tokens.split(',').skip(1).any(|attr| attr == SKIP_PATH)
+        });
+    }
+
+    let name = path
+        .segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::");
+    name == SKIP_PATH
+}
+
 /// Visitor for extracting semantic units from Rust AST
 pub struct SemanticUnitVisitor {
     units: Vec<SemanticUnit>,
     in_test_module: bool,
+    inherited_features: Vec<String>,
+    module_path: Vec<String>,
     current_impl_name: Option<String>,
     current_trait_visibility: Option<Visibility>,
 }
@@ -102,6 +159,8 @@ impl SemanticUnitVisitor {
         Self {
             units: Vec::new(),
             in_test_module: false,
+            inherited_features: Vec::new(),
+            module_path: Vec::new(),
             current_impl_name: None,
             current_trait_visibility: None,
         }
@@ -167,19 +226,30 @@ impl SemanticUnitVisitor {
             if !name.is_empty() {
                 attributes.push(name);
             }
+            if is_skip_attribute(attr) {
+                attributes.push(ATTRIBUTE_MARKER.to_string());
+            }
+        }
 
-            if path.is_ident("cfg")
+        for feature in self.cfg_features(attrs) {
+            attributes.push(format!("cfg_feature:{}", feature));
+        }
+
+        attributes
+    }
+
+    fn cfg_features(&self, attrs: &[Attribute]) -> Vec<String> {
+        let mut features = Vec::new();
+
+        for attr in attrs {
+            if attr.path().is_ident("cfg")
                 && let Ok(meta) = attr.meta.require_list()
             {
-                let mut features = Vec::new();
                 collect_cfg_features(meta.tokens.clone(), false, &mut features);
-                for feature in features {
-                    attributes.push(format!("cfg_feature:{}", feature));
-                }
             }
         }
 
-        attributes
+        features
     }
 
     fn has_test_attribute(&self, attrs: &[Attribute]) -> bool {
@@ -227,6 +297,13 @@ impl SemanticUnitVisitor {
             attributes.push("cfg_test".to_string());
         }
 
+        for feature in &self.inherited_features {
+            let marker = format!("cfg_feature:{}", feature);
+            if !attributes.contains(&marker) {
+                attributes.push(marker);
+            }
+        }
+
         if self.has_test_attribute(attrs) && !attributes.iter().any(|a| a == "test") {
             attributes.push("test".to_string());
         }
@@ -248,7 +325,8 @@ impl SemanticUnitVisitor {
                 attributes,
             ),
         };
-        self.units.push(unit);
+        self.units
+            .push(unit.with_module_path(self.module_path.clone()));
     }
 }
 
@@ -337,6 +415,10 @@ impl<'ast> Visit<'ast> for SemanticUnitVisitor {
             self.in_test_module = true;
         }
 
+        let inherited_len = self.inherited_features.len();
+        let features = self.cfg_features(&node.attrs);
+        self.inherited_features.extend(features);
+
         for item in &node.items {
             match item {
                 ImplItem::Fn(method) => {
@@ -370,6 +452,7 @@ impl<'ast> Visit<'ast> for SemanticUnitVisitor {
             }
         }
 
+        self.inherited_features.truncate(inherited_len);
         self.in_test_module = was_in_test;
         self.current_impl_name = previous_impl_name;
     }
@@ -413,6 +496,42 @@ impl<'ast> Visit<'ast> for SemanticUnitVisitor {
                 node.span(),
                 &node.attrs,
             );
+        } else if node
+            .mac
+            .path
+            .segments
+            .last()
+            .is_some_and(|s| s.ident == "fuzz_target")
+        {
+            self.add_unit(
+                SemanticUnitKind::Macro,
+                "fuzz_target".to_string(),
+                Visibility::Private,
+                node.span(),
+                &node.attrs,
+            );
+            if let Some(unit) = self.units.last_mut() {
+                unit.attributes.push("fuzz_target".to_string());
+            }
+        } else if self.module_path.is_empty() && is_out_dir_include_macro(node) {
+            // Inline modules are marked as a whole; a file-level include
+            // becomes a unit of its own so its lines are not left unmapped
+            let name = node
+                .mac
+                .path
+                .segments
+                .last()
+                .map_or_else(String::new, |s| s.ident.to_string());
+            self.add_unit(
+                SemanticUnitKind::Macro,
+                name,
+                Visibility::Private,
+                node.span(),
+                &node.attrs,
+            );
+            if let Some(unit) = self.units.last_mut() {
+                unit.attributes.push("generated_include".to_string());
+            }
         }
     }
 
@@ -428,13 +547,32 @@ impl<'ast> Visit<'ast> for SemanticUnitVisitor {
         );
 
         if let Some((_, items)) = &node.content {
+            if items.iter().any(is_out_dir_include)
+                && let Some(unit) = self.units.last_mut()
+            {
+                unit.attributes.push("generated_include".to_string());
+            }
+            if items.is_empty()
+                && node.attrs.iter().any(|attr| attr.path().is_ident("doc"))
+                && let Some(unit) = self.units.last_mut()
+            {
+                unit.attributes.push("doc_only".to_string());
+            }
+
             let was_in_test = self.in_test_module;
             self.in_test_module = is_test || was_in_test;
 
+            let inherited_len = self.inherited_features.len();
+            let features = self.cfg_features(&node.attrs);
+            self.inherited_features.extend(features);
+            self.module_path.push(node.ident.to_string());
+
             for item in items {
                 self.visit_item(item);
             }
 
+            self.module_path.pop();
+            self.inherited_features.truncate(inherited_len);
             self.in_test_module = was_in_test;
         }
     }
@@ -671,6 +809,91 @@ mod tests {
         assert!(!units[0].has_attribute("cfg_feature:mock"));
     }
 
+    #[test]
+    fn test_cfg_feature_inherited_from_module_and_impl() {
+        let code = r#"
+            #[cfg(feature = "mock")]
+            mod mocks {
+                pub fn fake() {}
+            }
+
+            struct Foo;
+
+            #[cfg(feature = "serde")]
+            impl Foo {
+                fn encode(&self) {}
+            }
+
+            fn plain() {}
+        "#;
+        let file = syn::parse_file(code).expect("parse failed");
+        let units = SemanticUnitVisitor::extract(&file);
+
+        let find = |name: &str| {
+            units
+                .iter()
+                .find(|u| u.name == name && !matches!(u.kind, SemanticUnitKind::Struct))
+                .expect("unit not found")
+        };
+        assert!(find("fake").has_attribute("cfg_feature:mock"));
+        assert!(find("encode").has_attribute("cfg_feature:serde"));
+        assert_eq!(find("plain").cfg_features().count(), 0);
+    }
+
+    #[test]
+    fn test_out_dir_include_marks_module() {
+        let code = r#"
+            pub mod proto {
+                include!(concat!(env!("OUT_DIR"), "/api.rs"));
+            }
+
+            mod fixtures {
+                include!("fixtures/data.rs");
+            }
+        "#;
+        let file = syn::parse_file(code).expect("parse failed");
+        let units = SemanticUnitVisitor::extract(&file);
+
+        let find = |name: &str| {
+            units
+                .iter()
+                .find(|u| u.name == name)
+                .expect("unit not found")
+        };
+        assert!(find("proto").has_attribute("generated_include"));
+        assert!(!find("fixtures").has_attribute("generated_include"));
+    }
+
+    #[test]
+    fn test_include_proto_marks_module() {
+        let code = r#"
+            pub mod api {
+                tonic::include_proto!("api.v1");
+            }
+        "#;
+        let file = syn::parse_file(code).expect("parse failed");
+        let units = SemanticUnitVisitor::extract(&file);
+
+        assert_eq!(units.len(), 1);
+        assert!(units[0].has_attribute("generated_include"));
+    }
+
+    #[test]
+    fn test_file_level_out_dir_include_is_unit() {
+        let code = r#"
+            include!(concat!(env!("OUT_DIR"), "/api.rs"));
+            include_proto!("events");
+            include!("fixtures/data.rs");
+        "#;
+        let file = syn::parse_file(code).expect("parse failed");
+        let units = SemanticUnitVisitor::extract(&file);
+
+        let names: Vec<_> = units.iter().map(|u| u.name.as_str()).collect();
+        assert_eq!(names, ["include", "include_proto"]);
+        assert!(units.iter().all(|u| u.has_attribute("generated_include")));
+        assert_eq!(units[0].span, LineSpan::new(2, 2));
+    }
+
     #[test]
     fn test_cfg_test_impl_marks_methods() {
         let code = r#"
@@ -723,4 +946,62 @@ mod tests {
             .expect("visible not found");
         assert!(matches!(visible.visibility, Visibility::Public));
     }
+
+    #[test]
+    fn test_module_path_tracks_inline_modules() {
+        let code = r#"
+            mod wire {
+                pub mod frame {
+                    impl Frame {
+                        fn decode() {}
+                    }
+                }
+                fn helper() {}
+            }
+            fn top() {}
+        "#;
+        let file = syn::parse_file(code).expect("parse failed");
+        let units = SemanticUnitVisitor::extract(&file);
+        let path = |name: &str| {
+            units
+                .iter()
+                .find(|u| u.name == name)
+                .map(|u| u.path_in_file())
+                .expect("unit")
+        };
+
+        assert_eq!(path("wire"), "wire");
+        assert_eq!(path("decode"), "wire::frame::Frame::decode");
+        assert_eq!(path("helper"), "wire::helper");
+        assert_eq!(path("top"), "top");
+    }
+
+    #[test]
+    fn test_fuzz_target_and_doc_only_markers() {
+        let code = r#"
+            fuzz_target!(|data: &[u8]| {
+                let _ = data;
+            });
+
+            /// Design notes
+            pub mod design {
+                //! Long-form explanation of the architecture.
+            }
+
+            /// Not documentation only
+            pub mod api {
+                pub fn call() {}
+            }
+        "#;
+        let file = syn::parse_file(code).expect("parse failed");
+        let units = SemanticUnitVisitor::extract(&file);
+
+        let fuzz = units
+            .iter()
+            .find(|u| u.name == "fuzz_target")
+            .expect("unit");
+        assert!(fuzz.has_attribute("fuzz_target"));
+        assert!(units[1].has_attribute("doc_only"));
+        assert!(!units[2].has_attribute("doc_only"));
+    }
 }
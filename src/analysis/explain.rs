@@ -0,0 +1,344 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{fmt::Write, path::Path};
+
+use masterror::AppError;
+
+use super::{extractor::extract_semantic_units_from_str, mapper::path_exclusion};
+use crate::{
+    classifier::header_classifier::generated_header_marker,
+    config::Config,
+    types::{ClassificationReason, CodeType, ExclusionReason, SemanticUnit},
+};
+
+/// Outcome of evaluating one classification rule against a unit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleEvaluation {
+    /// Rule identifier
+    pub rule: String,
+    /// Code type the rule assigns
+    pub code_type: CodeType,
+    /// Matching conditions, or `None` if the rule did not match
+    pub matched: Option<String>,
+}
+
+/// Classification decision chain for a single unit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnitExplanation {
+    /// The extracted unit
+    pub unit: SemanticUnit,
+    /// Resulting classification
+    pub classification: CodeType,
+    /// What decided the classification
+    pub reason: ClassificationReason,
+    /// Rules evaluated in order, up to and including the deciding one
+    pub evaluations: Vec<RuleEvaluation>,
+}
+
+/// Classification decisions for the units of a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileExplanation {
+    /// Why the file would be skipped during analysis, if it would
+    pub exclusion: Option<ExclusionReason>,
+    /// Units of the file, outermost first
+    pub units: Vec<UnitExplanation>,
+}
+
+/// Explains how a unit is classified
+///
+/// # Arguments
+///
+/// * `unit` - Semantic unit
+/// * `path` - Path of the file containing the unit
+/// * `config` - Configuration
+///
+/// # Returns
+///
+/// Decision chain for the unit
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_diff_analyzer::{
+///     analysis::explain::explain_unit,
+///     config::Config,
+///     types::{CodeType, LineSpan, SemanticUnit, SemanticUnitKind, Visibility},
+/// };
+///
+/// let unit = SemanticUnit::new(
+///     SemanticUnitKind::Function,
+///     "it_works".to_string(),
+///     Visibility::Private,
+///     LineSpan::new(1, 3),
+///     vec!["test".to_string()],
+/// );
+///
+/// let explanation = explain_unit(&unit, Path::new("src/lib.rs"), &Config::default());
+/// assert_eq!(explanation.classification, CodeType::Test);
+/// assert!(explanation.evaluations.last().unwrap().matched.is_some());
+/// ```
+pub fn explain_unit(unit: &SemanticUnit, path: &Path, config: &Config) -> UnitExplanation {
+    let mut evaluations = Vec::new();
+
+    for (index, rule) in config.classification_rules().iter().enumerate() {
+        let label = rule.label(index);
+        if rule.matches(unit, path, config) {
+            let matched = rule.describe_match(unit, path, config);
+            evaluations.push(RuleEvaluation {
+                rule: label.clone(),
+                code_type: rule.code_type,
+                matched: Some(matched.clone()),
+            });
+            return UnitExplanation {
+                unit: unit.clone(),
+                classification: rule.code_type,
+                reason: ClassificationReason::Rule {
+                    rule: label,
+                    matched,
+                },
+                evaluations,
+            };
+        }
+
+        evaluations.push(RuleEvaluation {
+            rule: label,
+            code_type: rule.code_type,
+            matched: None,
+        });
+    }
+
+    UnitExplanation {
+        unit: unit.clone(),
+        classification: CodeType::Production,
+        reason: ClassificationReason::Default,
+        evaluations,
+    }
+}
+
+/// Explains how the units of a source file are classified
+///
+/// # Arguments
+///
+/// * `content` - Source file content
+/// * `path` - Repository-relative path of the file
+/// * `line` - Optional line; only units containing it are explained
+/// * `config` - Configuration
+///
+/// # Returns
+///
+/// Explanation for the file or error
+///
+/// # Errors
+///
+/// Returns error if the content cannot be parsed as Rust
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_diff_analyzer::{analysis::explain::explain_file, config::Config, types::CodeType};
+///
+/// let code = "pub fn run() {}\n\n#[cfg(test)]\nmod tests {\n    fn helper() {}\n}\n";
+/// let explanation =
+///     explain_file(code, Path::new("src/lib.rs"), Some(5), &Config::default()).unwrap();
+///
+/// let names: Vec<_> = explanation
+///     .units
+///     .iter()
+///     .map(|u| u.unit.name.as_str())
+///     .collect();
+/// assert_eq!(names, ["tests", "helper"]);
+/// assert_eq!(explanation.units[1].classification, CodeType::TestUtility);
+/// ```
+pub fn explain_file(
+    content: &str,
+    path: &Path,
+    line: Option<usize>,
+    config: &Config,
+) -> Result<FileExplanation, AppError> {
+    let exclusion = if path.extension().is_some_and(|ext| ext == "rs") {
+        path_exclusion(path, config)
+    } else {
+        Some(ExclusionReason::NonRust)
+    };
+
+    let marker = generated_header_marker(content, &config.classification.generated_markers);
+
+    let units = extract_semantic_units_from_str(content, path)?
+        .into_iter()
+        .filter(|unit| line.is_none_or(|line| unit.span.contains(line)))
+        .map(|unit| match marker {
+            Some(marker) => UnitExplanation {
+                unit,
+                classification: CodeType::Generated,
+                reason: ClassificationReason::GeneratedHeader {
+                    marker: marker.to_string(),
+                },
+                evaluations: Vec::new(),
+            },
+            None => explain_unit(&unit, path, config),
+        })
+        .collect();
+
+    Ok(FileExplanation { exclusion, units })
+}
+
+/// Formats a file explanation as plain text
+///
+/// # Arguments
+///
+/// * `path` - Path of the explained file
+/// * `explanation` - File explanation
+///
+/// # Returns
+///
+/// Multi-line report listing each unit and its decision chain
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_diff_analyzer::{
+///     analysis::explain::{explain_file, format_explanation},
+///     config::Config,
+/// };
+///
+/// let path = Path::new("src/lib.rs");
+/// let explanation = explain_file("pub fn run() {}\n", path, None, &Config::default()).unwrap();
+/// let report = format_explanation(path, &explanation);
+/// assert!(report.contains("function `run`"));
+/// assert!(report.contains("no rule matched (default) -> production"));
+/// ```
+pub fn format_explanation(path: &Path, explanation: &FileExplanation) -> String {
+    let mut output = String::new();
+
+    let _ = writeln!(output, "{}", path.display());
+
+    if let Some(exclusion) = &explanation.exclusion {
+        let reason = match exclusion {
+            ExclusionReason::NonRust => "it is not a Rust file".to_string(),
+            ExclusionReason::IgnorePattern(p) => format!("it matches ignore pattern `{}`", p),
+            ExclusionReason::GitAttribute(a) => format!(".gitattributes sets `{}`", a),
+            ExclusionReason::Deleted => "it is deleted".to_string(),
+            ExclusionReason::Binary => "it is binary".to_string(),
+            ExclusionReason::ReadError(e) | ExclusionReason::ParseError(e) => e.clone(),
+        };
+        let _ = writeln!(
+            output,
+            "  note: file is skipped during analysis because {}",
+            reason
+        );
+    }
+
+    if explanation.units.is_empty() {
+        output.push_str("  no semantic units found\n");
+    }
+
+    for explanation in &explanation.units {
+        let unit = &explanation.unit;
+        let _ = writeln!(
+            output,
+            "\n  {} `{}` ({}, lines {}-{}) -> {}",
+            unit.kind.as_str(),
+            unit.qualified_name(),
+            unit.visibility.as_str(),
+            unit.span.start,
+            unit.span.end,
+            explanation.classification.as_str()
+        );
+        let attributes: Vec<&str> = unit
+            .attributes
+            .iter()
+            .map(String::as_str)
+            .filter(|a| *a != "doc")
+            .collect();
+        if !attributes.is_empty() {
+            let _ = writeln!(output, "    attributes: {}", attributes.join(", "));
+        }
+
+        for evaluation in &explanation.evaluations {
+            match &evaluation.matched {
+                Some(matched) => {
+                    let _ = writeln!(
+                        output,
+                        "    + {}: {} -> {}",
+                        evaluation.rule,
+                        matched,
+                        evaluation.code_type.as_str()
+                    );
+                }
+                None => {
+                    let _ = writeln!(output, "    - {}: no match", evaluation.rule);
+                }
+            }
+        }
+
+        match &explanation.reason {
+            ClassificationReason::Rule { .. } => {}
+            reason => {
+                let _ = writeln!(
+                    output,
+                    "    = {} -> {}",
+                    reason.describe(),
+                    explanation.classification.as_str()
+                );
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_stops_at_deciding_rule() {
+        let code = "#[cfg(feature = \"mock\")]\npub fn fake() {}\n";
+        let explanation = explain_file(code, Path::new("src/lib.rs"), None, &Config::default())
+            .expect("explain should work");
+
+        let unit = &explanation.units[0];
+        assert_eq!(unit.classification, CodeType::TestUtility);
+        let last = unit.evaluations.last().expect("evaluations");
+        assert_eq!(last.rule, "test-feature");
+        assert_eq!(
+            last.matched.as_deref(),
+            Some("gated by test feature `mock`")
+        );
+        assert!(
+            unit.evaluations[..unit.evaluations.len() - 1]
+                .iter()
+                .all(|e| e.matched.is_none())
+        );
+    }
+
+    #[test]
+    fn test_excluded_and_generated_files_are_reported() {
+        let mut config = Config::default();
+        config
+            .classification
+            .ignore_paths
+            .push("proto/".to_string());
+
+        let path = Path::new("proto/api.rs");
+        let explanation = explain_file("// @generated\npub struct Msg;\n", path, None, &config)
+            .expect("explain should work");
+
+        assert_eq!(
+            explanation.exclusion,
+            Some(ExclusionReason::IgnorePattern("proto/".to_string()))
+        );
+        assert_eq!(explanation.units[0].classification, CodeType::Generated);
+
+        let report = format_explanation(path, &explanation);
+        assert!(report.contains("matches ignore pattern `proto/`"));
+        assert!(report.contains("= generated file header `@generated` -> generated"));
+    }
+}
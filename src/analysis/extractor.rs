@@ -5,10 +5,10 @@ use std::{fs, path::Path};
 
 use masterror::AppError;
 
-use super::ast_visitor::SemanticUnitVisitor;
+use super::{ast_visitor::SemanticUnitVisitor, suppression::apply_suppressions};
 use crate::{
     error::{FileReadError, ParseError},
-    types::SemanticUnit,
+    types::{LineSpan, SemanticUnit, SemanticUnitKind, Visibility},
 };
 
 /// Extracts semantic units from a Rust source file
@@ -43,6 +43,11 @@ pub fn extract_semantic_units(path: &Path) -> Result<Vec<SemanticUnit>, AppError
 
 /// Extracts semantic units from Rust source code string
 ///
+/// Units opted out with a suppression attribute or an
+/// `ignore-next-item` comment carry the corresponding marker. A file with
+/// `//!` docs and no items yields a single module unit spanning the file,
+/// marked `doc_only`.
+///
 /// # Arguments
 ///
 /// * `content` - Rust source code as string
@@ -74,7 +79,33 @@ pub fn extract_semantic_units_from_str(
     let file = syn::parse_file(content)
         .map_err(|e| AppError::from(ParseError::new(path, e.to_string())))?;
 
-    Ok(SemanticUnitVisitor::extract(&file))
+    let mut units = SemanticUnitVisitor::extract(&file);
+    if let Some(unit) = doc_only_file_unit(&file, content, path) {
+        units.push(unit);
+    }
+    apply_suppressions(&mut units, content);
+
+    Ok(units)
+}
+
+/// Builds the unit representing a documentation-only file
+fn doc_only_file_unit(file: &syn::File, content: &str, path: &Path) -> Option<SemanticUnit> {
+    let documented = file.attrs.iter().any(|attr| attr.path().is_ident("doc"));
+    if !file.items.is_empty() || !documented {
+        return None;
+    }
+
+    let name = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    Some(SemanticUnit::new(
+        SemanticUnitKind::Module,
+        name,
+        Visibility::Private,
+        LineSpan::new(1, content.lines().count().max(1)),
+        vec!["doc".to_string(), "doc_only".to_string()],
+    ))
 }
 
 #[cfg(test)]
@@ -103,4 +134,21 @@ mod tests {
         let result = extract_semantic_units_from_str(bad_code, Path::new("bad.rs"));
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_doc_only_file_yields_module_unit() {
+        let code = "//! # Architecture\n//!\n//! How the pieces fit together.\n";
+        let units = extract_semantic_units_from_str(code, Path::new("src/docs.rs"))
+            .expect("extraction should succeed");
+
+        assert_eq!(units.len(), 1);
+        assert_eq!(units[0].name, "docs");
+        assert_eq!(units[0].span, LineSpan::new(1, 3));
+        assert!(units[0].has_attribute("doc_only"));
+
+        let code = "//! Crate docs\npub fn run() {}\n";
+        let units = extract_semantic_units_from_str(code, Path::new("src/lib.rs"))
+            .expect("extraction should succeed");
+        assert!(units.iter().all(|u| !u.has_attribute("doc_only")));
+    }
 }
@@ -1,16 +1,26 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
-use std::{collections::HashMap, path::Path};
+use std::{collections::HashMap, io, path::Path};
 
 use masterror::AppError;
 
-use super::extractor::extract_semantic_units_from_str;
+use super::{
+    extractor::extract_semantic_units_from_str,
+    suppression::{ignores_file, unit_suppression},
+};
 use crate::{
-    classifier::classify_unit,
+    classifier::{
+        classify_unit_with_rules, engine::ClassificationRule,
+        header_classifier::generated_header_marker,
+    },
     config::Config,
     git::FileDiff,
-    types::{AnalysisScope, Change, ExclusionReason, SemanticUnit},
+    source::{Revision, SourceProvider, decode_text, is_binary},
+    types::{
+        AnalysisScope, Change, ClassificationReason, CodeType, ExclusionReason, SemanticUnit,
+        Suppression,
+    },
 };
 
 /// Result of mapping changes including scope information
@@ -23,11 +33,18 @@ pub struct MapResult {
 
 /// Maps diff changes to semantic units
 ///
+/// Contents of all analyzed files are requested from the source provider
+/// in one batch: the new revision for every file and, for files with
+/// removed lines, the old revision as well. When the old content is
+/// available, a removed line inside a unit that still exists after the
+/// change is attributed to that unit; otherwise it is attributed to the
+/// unit occupying its position in the new file.
+///
 /// # Arguments
 ///
 /// * `diffs` - Vector of file diffs
 /// * `config` - Configuration
-/// * `file_reader` - Function to read file contents
+/// * `source` - Provider of old and new file contents
 ///
 /// # Returns
 ///
@@ -41,47 +58,79 @@ pub struct MapResult {
 /// # Examples
 ///
 /// ```no_run
-/// use std::fs;
-///
-/// use rust_diff_analyzer::{analysis::map_changes, config::Config};
+/// use rust_diff_analyzer::{analysis::map_changes, config::Config, source::FsSource};
 ///
 /// let diffs = vec![];
 /// let config = Config::default();
-/// let result = map_changes(&diffs, &config, |p| fs::read_to_string(p));
+/// let result = map_changes(&diffs, &config, &FsSource::new("."));
 /// ```
-pub fn map_changes<F>(
+pub fn map_changes<S>(
+    diffs: &[FileDiff],
+    config: &Config,
+    source: &S,
+) -> Result<MapResult, AppError>
+where
+    S: SourceProvider + ?Sized,
+{
+    map_changes_with_rules(diffs, config, &config.classification_rules(), source)
+}
+
+/// Maps diff changes to semantic units with a prebuilt rule set
+///
+/// See [`map_changes`]; `rules` are the classification rules in effect,
+/// built once by the caller.
+pub(crate) fn map_changes_with_rules<S>(
     diffs: &[FileDiff],
     config: &Config,
-    file_reader: F,
+    rules: &[ClassificationRule],
+    source: &S,
 ) -> Result<MapResult, AppError>
 where
-    F: Fn(&Path) -> Result<String, std::io::Error>,
+    S: SourceProvider + ?Sized,
 {
     let mut changes = Vec::new();
     let mut scope = AnalysisScope::new();
 
     scope.set_patterns(config.classification.ignore_paths.clone());
 
-    for diff in diffs {
-        if !diff.is_rust_file() {
-            scope.add_skipped(diff.path.clone(), ExclusionReason::NonRust);
-            continue;
+    let exclusions: Vec<Option<ExclusionReason>> = diffs
+        .iter()
+        .map(|diff| diff_exclusion(diff, config))
+        .collect();
+    let mut requests = Vec::new();
+    for (diff, exclusion) in diffs.iter().zip(&exclusions) {
+        if exclusion.is_none() {
+            requests.push((diff.path.clone(), Revision::New));
+            if wants_old_content(diff) {
+                let old_path = diff.old_path.as_ref().unwrap_or(&diff.path);
+                requests.push((old_path.clone(), Revision::Old));
+            }
         }
+    }
+    let mut contents = source.read_batch(&requests).into_iter();
 
-        if diff.is_deleted {
-            scope.add_skipped(diff.path.clone(), ExclusionReason::Deleted);
+    for (diff, exclusion) in diffs.iter().zip(exclusions) {
+        if let Some(reason) = exclusion {
+            scope.add_skipped(diff.path.clone(), reason);
             continue;
         }
 
-        if let Some(pattern) = config.matched_ignore_pattern(&diff.path) {
-            scope.add_skipped(
-                diff.path.clone(),
-                ExclusionReason::IgnorePattern(pattern.to_string()),
-            );
-            continue;
-        }
+        let new_content = contents.next();
+        let old_content = if wants_old_content(diff) {
+            contents.next()
+        } else {
+            None
+        };
 
-        let content = match file_reader(&diff.path) {
+        let content = match new_content.unwrap_or_else(|| Err(io::Error::other("not read"))) {
+            Ok(bytes) if is_binary(&bytes) => {
+                scope.add_skipped(diff.path.clone(), ExclusionReason::Binary);
+                continue;
+            }
+            Ok(bytes) => decode_text(bytes),
+            Err(e) => Err(e),
+        };
+        let content = match content {
             Ok(content) => content,
             Err(e) => {
                 scope.add_skipped(diff.path.clone(), ExclusionReason::ReadError(e.to_string()));
@@ -102,7 +151,22 @@ where
 
         scope.add_analyzed(diff.path.clone());
 
+        let old_units = old_content
+            .and_then(Result::ok)
+            .filter(|bytes| !is_binary(bytes))
+            .and_then(|bytes| decode_text(bytes).ok())
+            .and_then(|old| extract_semantic_units_from_str(&old, &diff.path).ok());
+
+        let generated_header =
+            generated_header_marker(&content, &config.classification.generated_markers);
+        if generated_header.is_some() || config.is_generated_path(&diff.path) {
+            scope.add_generated(diff.path.clone());
+        }
+
+        let file_ignored = ignores_file(&content);
+
         let added_lines = diff.all_added_lines();
+        let removed_lines = diff.all_removed_lines();
         let removed_positions = diff.all_removed_positions_in_new();
 
         let mut unit_changes: HashMap<usize, (usize, usize)> = HashMap::new();
@@ -114,8 +178,12 @@ where
             }
         }
 
-        for line in &removed_positions {
-            if let Some(index) = find_containing_unit_index(&units, *line) {
+        for (old_line, position) in removed_lines.iter().zip(&removed_positions) {
+            let index = old_units
+                .as_deref()
+                .and_then(|old_units| surviving_unit_index(&units, old_units, *old_line))
+                .or_else(|| find_containing_unit_index(&units, *position));
+            if let Some(index) = index {
                 let entry = unit_changes.entry(index).or_insert((0, 0));
                 entry.1 += 1;
             }
@@ -123,15 +191,37 @@ where
 
         for (index, unit) in units.iter().enumerate() {
             if let Some((added, removed)) = unit_changes.get(&index) {
-                let classification = classify_unit(unit, &diff.path, config);
-
-                changes.push(Change::new(
+                let (classification, reason) = match generated_header {
+                    Some(marker) => (
+                        CodeType::Generated,
+                        ClassificationReason::GeneratedHeader {
+                            marker: marker.to_string(),
+                        },
+                    ),
+                    None => classify_unit_with_rules(unit, &diff.path, config, rules),
+                };
+
+                let mut change = Change::new(
                     diff.path.clone(),
                     unit.clone(),
                     classification,
                     *added,
                     *removed,
-                ));
+                )
+                .with_reason(reason);
+                let suppression = unit_suppression(unit, file_ignored).or_else(|| {
+                    config
+                        .allowlist_entry(unit, &diff.path)
+                        .map(|entry| Suppression::Allowlist {
+                            pattern: entry.pattern.clone(),
+                            reason: entry.reason.clone(),
+                        })
+                });
+                if let Some(suppression) = suppression {
+                    change = change.with_suppression(suppression);
+                }
+
+                changes.push(change);
             }
         }
     }
@@ -139,6 +229,82 @@ where
     Ok(MapResult { changes, scope })
 }
 
+/// Returns why configuration excludes a path from analysis, if it does
+///
+/// Covers `ignore_paths` and `.gitattributes` exclusions; non-Rust and
+/// deleted files are handled by the caller.
+///
+/// # Arguments
+///
+/// * `path` - Repository-relative path
+/// * `config` - Configuration
+///
+/// # Returns
+///
+/// Exclusion reason or `None` if the path is analyzed
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_diff_analyzer::{
+///     analysis::mapper::path_exclusion, config::ConfigBuilder, types::ExclusionReason,
+/// };
+///
+/// let config = ConfigBuilder::new().add_ignore_path("vendor/").build();
+/// assert_eq!(
+///     path_exclusion(Path::new("vendor/dep.rs"), &config),
+///     Some(ExclusionReason::IgnorePattern("vendor/".to_string()))
+/// );
+/// assert_eq!(path_exclusion(Path::new("src/lib.rs"), &config), None);
+/// ```
+pub fn path_exclusion(path: &Path, config: &Config) -> Option<ExclusionReason> {
+    if let Some(pattern) = config.matched_ignore_pattern(path) {
+        return Some(ExclusionReason::IgnorePattern(pattern.to_string()));
+    }
+
+    config
+        .workspace
+        .attributes
+        .exclusion(path)
+        .map(ExclusionReason::GitAttribute)
+}
+
+/// Returns why a diff is skipped before any content is read
+fn diff_exclusion(diff: &FileDiff, config: &Config) -> Option<ExclusionReason> {
+    if !diff.is_rust_file() {
+        Some(ExclusionReason::NonRust)
+    } else if diff.is_deleted {
+        Some(ExclusionReason::Deleted)
+    } else {
+        path_exclusion(&diff.path, config)
+    }
+}
+
+/// Old content is only needed to attribute removed lines
+fn wants_old_content(diff: &FileDiff) -> bool {
+    diff.total_removed() > 0
+}
+
+/// Finds the new unit matching the old unit that contained a removed line
+///
+/// Units match when kind and qualified name agree and the match is unique.
+fn surviving_unit_index(
+    units: &[SemanticUnit],
+    old_units: &[SemanticUnit],
+    old_line: usize,
+) -> Option<usize> {
+    let old = &old_units[find_containing_unit_index(old_units, old_line)?];
+    let mut matches = units.iter().enumerate().filter(|(_, unit)| {
+        unit.kind == old.kind && unit.qualified_name() == old.qualified_name()
+    });
+    match (matches.next(), matches.next()) {
+        (Some((index, _)), None) => Some(index),
+        _ => None,
+    }
+}
+
 fn find_containing_unit_index(units: &[SemanticUnit], line: usize) -> Option<usize> {
     let mut best_match: Option<usize> = None;
 
@@ -161,7 +327,26 @@ fn find_containing_unit_index(units: &[SemanticUnit], line: usize) -> Option<usi
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{LineSpan, SemanticUnitKind, Visibility};
+    use crate::{
+        source::MemorySource,
+        types::{LineSpan, SemanticUnitKind, Visibility},
+    };
+
+    fn new_file(path: &str, content: &str) -> MemorySource {
+        MemorySource::new().with_file(Revision::New, path, content)
+    }
+
+    /// Source that fails every read, for paths that must not be read
+    struct Unreadable;
+
+    impl SourceProvider for Unreadable {
+        fn read(&self, path: &Path, _revision: Revision) -> io::Result<Vec<u8>> {
+            Err(io::Error::other(format!(
+                "must not be read: {}",
+                path.display()
+            )))
+        }
+    }
 
     #[test]
     fn test_find_containing_unit_index() {
@@ -231,8 +416,8 @@ fn second() {
         diff.hunks = vec![hunk_top, hunk_inner];
 
         let config = Config::default();
-        let result =
-            map_changes(&[diff], &config, |_| Ok(content.to_string())).expect("map should work");
+        let result = map_changes(&[diff], &config, &new_file("src/prod.rs", content))
+            .expect("map should work");
 
         let second = result
             .changes
@@ -281,8 +466,8 @@ impl Foo {
         diff.hunks = vec![hunk];
 
         let config = Config::default();
-        let result =
-            map_changes(&[diff], &config, |_| Ok(content.to_string())).expect("map should work");
+        let result = map_changes(&[diff], &config, &new_file("src/prod.rs", content))
+            .expect("map should work");
 
         assert_eq!(result.changes.len(), 1);
         assert_eq!(result.changes[0].unit.name, "Foo");
@@ -291,17 +476,15 @@ impl Foo {
 
     #[test]
     fn test_unreadable_file_is_skipped_not_fatal() {
-        use std::{io, path::PathBuf};
+        use std::path::PathBuf;
 
         use crate::{config::Config, git::FileDiff};
 
         let diff = FileDiff::new(PathBuf::from("src/missing.rs"));
         let config = Config::default();
 
-        let result = map_changes(&[diff], &config, |_| {
-            Err(io::Error::new(io::ErrorKind::NotFound, "no such file"))
-        })
-        .expect("read failure must not abort analysis");
+        let result = map_changes(&[diff], &config, &MemorySource::new())
+            .expect("read failure must not abort analysis");
 
         assert!(result.changes.is_empty());
         assert!(result.scope.analyzed_files.is_empty());
@@ -321,8 +504,12 @@ impl Foo {
         let diff = FileDiff::new(PathBuf::from("src/broken.rs"));
         let config = Config::default();
 
-        let result = map_changes(&[diff], &config, |_| Ok("fn broken( {{{".to_string()))
-            .expect("parse failure must not abort analysis");
+        let result = map_changes(
+            &[diff],
+            &config,
+            &new_file("src/broken.rs", "fn broken( {{{"),
+        )
+        .expect("parse failure must not abort analysis");
 
         assert!(result.changes.is_empty());
         assert_eq!(result.scope.error_count(), 1);
@@ -334,7 +521,7 @@ impl Foo {
 
     #[test]
     fn test_deleted_file_is_skipped_without_reading() {
-        use std::{io, path::PathBuf};
+        use std::path::PathBuf;
 
         use crate::{config::Config, git::FileDiff};
 
@@ -342,13 +529,8 @@ impl Foo {
         diff.is_deleted = true;
         let config = Config::default();
 
-        let result = map_changes(&[diff], &config, |path| {
-            Err(io::Error::new(
-                io::ErrorKind::NotFound,
-                format!("must not be read: {}", path.display()),
-            ))
-        })
-        .expect("deleted file must not abort analysis");
+        let result = map_changes(&[diff], &config, &Unreadable)
+            .expect("deleted file must not abort analysis");
 
         assert!(result.changes.is_empty());
         assert_eq!(result.scope.skipped_files.len(), 1);
@@ -357,4 +539,136 @@ impl Foo {
             ExclusionReason::Deleted
         );
     }
+
+    #[test]
+    fn test_generated_header_classifies_whole_file() {
+        use std::path::PathBuf;
+
+        use crate::{
+            config::Config,
+            git::{FileDiff, Hunk, HunkLine},
+        };
+
+        let content = "\
+// This file is @generated by prost-build.
+pub struct Request {
+    pub id: u64,
+}
+";
+
+        let mut hunk = Hunk::new(0, 0, 1, 4);
+        for (line, text) in content.lines().enumerate() {
+            hunk.lines.push(HunkLine::added(line + 1, text.to_string()));
+        }
+
+        let mut diff = FileDiff::new(PathBuf::from("src/api.rs"));
+        diff.hunks = vec![hunk];
+
+        let config = Config::default();
+        let result = map_changes(&[diff], &config, &new_file("src/api.rs", content))
+            .expect("map should work");
+
+        assert_eq!(result.changes.len(), 1);
+        assert_eq!(result.changes[0].classification, CodeType::Generated);
+        assert_eq!(
+            result.scope.generated_files,
+            vec![PathBuf::from("src/api.rs")]
+        );
+    }
+
+    #[test]
+    fn test_gitattributes_exclusion_is_skipped_without_reading() {
+        use std::path::PathBuf;
+
+        use crate::{
+            config::Config,
+            git::{FileDiff, GitAttributes},
+        };
+
+        let mut config = Config::default();
+        config.workspace.attributes = GitAttributes::parse("third_party/** linguist-vendored\n");
+
+        let diff = FileDiff::new(PathBuf::from("third_party/dep/lib.rs"));
+        let result = map_changes(&[diff], &config, &Unreadable).expect("map should work");
+
+        assert!(result.scope.analyzed_files.is_empty());
+        assert!(result.changes.is_empty());
+        assert_eq!(result.scope.skipped_files.len(), 1);
+        assert_eq!(
+            result.scope.skipped_files[0].reason,
+            ExclusionReason::GitAttribute("linguist-vendored".to_string())
+        );
+    }
+
+    #[test]
+    fn test_removed_lines_attributed_to_surviving_old_unit() {
+        use std::path::PathBuf;
+
+        use crate::{
+            config::Config,
+            git::{FileDiff, Hunk, HunkLine},
+        };
+
+        let old = "\
+mod outer {
+    fn a() {}
+    // note
+}
+";
+        let new = "\
+mod outer {
+    fn a() {}
+    fn b() {}
+}
+";
+
+        let mut hunk = Hunk::new(1, 4, 1, 4);
+        hunk.lines
+            .push(HunkLine::context(1, 1, "mod outer {".to_string()));
+        hunk.lines
+            .push(HunkLine::context(2, 2, "    fn a() {}".to_string()));
+        hunk.lines
+            .push(HunkLine::removed(3, "    // note".to_string()));
+        hunk.lines
+            .push(HunkLine::added(3, "    fn b() {}".to_string()));
+        hunk.lines.push(HunkLine::context(4, 4, "}".to_string()));
+
+        let mut diff = FileDiff::new(PathBuf::from("src/prod.rs"));
+        diff.hunks = vec![hunk];
+        let config = Config::default();
+        let removed_from = |result: &MapResult| -> Vec<(String, usize)> {
+            result
+                .changes
+                .iter()
+                .filter(|c| c.lines_removed > 0)
+                .map(|c| (c.unit.name.clone(), c.lines_removed))
+                .collect()
+        };
+
+        let without_old = map_changes(&[diff.clone()], &config, &new_file("src/prod.rs", new))
+            .expect("map should work");
+        assert_eq!(removed_from(&without_old), [("b".to_string(), 1)]);
+
+        let source = new_file("src/prod.rs", new).with_file(Revision::Old, "src/prod.rs", old);
+        let result = map_changes(&[diff], &config, &source).expect("map should work");
+        assert_eq!(removed_from(&result), [("outer".to_string(), 1)]);
+    }
+
+    #[test]
+    fn test_binary_file_is_skipped() {
+        use std::path::PathBuf;
+
+        use crate::{config::Config, git::FileDiff};
+
+        let diff = FileDiff::new(PathBuf::from("src/blob.rs"));
+        let source =
+            MemorySource::new().with_file(Revision::New, "src/blob.rs", b"\0\x01".to_vec());
+        let result = map_changes(&[diff], &Config::default(), &source).expect("map should work");
+
+        assert_eq!(
+            result.scope.skipped_files[0].reason,
+            ExclusionReason::Binary
+        );
+        assert_eq!(result.scope.error_count(), 0);
+    }
 }
@@ -0,0 +1,346 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use crate::types::{SemanticUnit, SemanticUnitKind, Suppression};
+
+/// Unit marker set by the visitor for `rust_diff_analyzer::skip` attributes
+pub const ATTRIBUTE_MARKER: &str = "suppress:attribute";
+
+/// Unit marker set for `// rust-diff-analyzer: ignore-next-item` comments
+pub const COMMENT_MARKER: &str = "suppress:comment";
+
+/// Prefix introducing an analyzer directive in a line comment
+const DIRECTIVE_PREFIX: &str = "rust-diff-analyzer:";
+
+/// Directive given in a `// rust-diff-analyzer: <directive>` comment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Directive {
+    /// Suppress the item starting after the comment
+    IgnoreNextItem,
+    /// Suppress every unit of the file
+    IgnoreFile,
+}
+
+/// Parses the analyzer directive of a source line, if it has one
+///
+/// The directive must be the first word after the prefix; anything after it
+/// is free-form explanation. Doc comments are never directives.
+///
+/// # Arguments
+///
+/// * `line` - Source line
+///
+/// # Returns
+///
+/// Parsed directive or `None`
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::analysis::suppression::{Directive, parse_directive};
+///
+/// assert_eq!(
+///     parse_directive("    // rust-diff-analyzer: ignore-next-item (opcode table)"),
+///     Some(Directive::IgnoreNextItem)
+/// );
+/// assert_eq!(parse_directive("/// rust-diff-analyzer: ignore-file"), None);
+/// ```
+pub fn parse_directive(line: &str) -> Option<Directive> {
+    let comment = line.trim_start().strip_prefix("//")?;
+    if comment.starts_with('/') || comment.starts_with('!') {
+        return None;
+    }
+
+    let body = comment.trim_start().strip_prefix(DIRECTIVE_PREFIX)?;
+    match body.split_whitespace().next()? {
+        "ignore-next-item" => Some(Directive::IgnoreNextItem),
+        "ignore-file" => Some(Directive::IgnoreFile),
+        _ => None,
+    }
+}
+
+/// Checks whether a file suppresses all of its units
+///
+/// Only the leading block of line comments and blank lines is searched, so
+/// a marker inside a string literal or a function body has no effect.
+///
+/// # Arguments
+///
+/// * `content` - Source file content
+///
+/// # Returns
+///
+/// `true` if a `// rust-diff-analyzer: ignore-file` comment precedes all
+/// code in the file
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::analysis::suppression::ignores_file;
+///
+/// assert!(ignores_file(
+///     "// SPDX-License-Identifier: MIT\n// rust-diff-analyzer: ignore-file\npub fn table() {}\n"
+/// ));
+/// assert!(!ignores_file(
+///     "pub fn table() {}\n// rust-diff-analyzer: ignore-file\n"
+/// ));
+/// ```
+pub fn ignores_file(content: &str) -> bool {
+    content
+        .lines()
+        .map(str::trim_start)
+        .take_while(|line| line.is_empty() || line.starts_with("//"))
+        .any(|line| parse_directive(line) == Some(Directive::IgnoreFile))
+}
+
+/// Marks units suppressed by comments and propagates suppression inward
+///
+/// An `ignore-next-item` comment suppresses the unit starting right below
+/// it, so it must sit directly above the item's doc comments and
+/// attributes. Comments at item level only count: inside a module, impl or
+/// trait, but not inside a function or other unit body. Units nested inside
+/// a suppressed unit (methods of an impl, items of a module) inherit its
+/// marker.
+///
+/// # Arguments
+///
+/// * `units` - Units extracted from the file, outermost first
+/// * `content` - Source file content
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_diff_analyzer::analysis::{
+///     extractor::extract_semantic_units_from_str, suppression::COMMENT_MARKER,
+/// };
+///
+/// let code = "// rust-diff-analyzer: ignore-next-item\nimpl Codec {\n    fn decode() {}\n}\n";
+/// let units = extract_semantic_units_from_str(code, Path::new("src/lib.rs")).unwrap();
+/// assert!(units.iter().all(|u| u.has_attribute(COMMENT_MARKER)));
+/// ```
+pub fn apply_suppressions(units: &mut [SemanticUnit], content: &str) {
+    let lines: Vec<&str> = content.lines().collect();
+    for (index, line) in lines.iter().enumerate() {
+        if parse_directive(line) != Some(Directive::IgnoreNextItem) {
+            continue;
+        }
+
+        let marker_line = index + 1;
+        let in_body = units.iter().any(|unit| {
+            !matches!(
+                unit.kind,
+                SemanticUnitKind::Module | SemanticUnitKind::Impl | SemanticUnitKind::Trait
+            ) && unit.span.contains(marker_line)
+        });
+        if in_body {
+            continue;
+        }
+
+        // Attributes and doc comments may sit between the marker and the
+        // item; the unit must start within them or on the item line itself
+        let item_line = marker_line
+            + 1
+            + lines[marker_line..]
+                .iter()
+                .take_while(|line| {
+                    let line = line.trim_start();
+                    line.starts_with("#[") || line.starts_with("//")
+                })
+                .count();
+        let target = units
+            .iter_mut()
+            .filter(|unit| unit.span.start > marker_line && unit.span.start <= item_line)
+            .min_by_key(|unit| unit.span.start);
+        if let Some(unit) = target
+            && !unit.has_attribute(COMMENT_MARKER)
+        {
+            unit.attributes.push(COMMENT_MARKER.to_string());
+        }
+    }
+
+    for marker in [ATTRIBUTE_MARKER, COMMENT_MARKER] {
+        let spans: Vec<_> = units
+            .iter()
+            .filter(|unit| unit.has_attribute(marker))
+            .map(|unit| unit.span)
+            .collect();
+
+        for unit in units.iter_mut() {
+            let enclosed = spans
+                .iter()
+                .any(|span| span.start <= unit.span.start && unit.span.end <= span.end);
+            if enclosed && !unit.has_attribute(marker) {
+                unit.attributes.push(marker.to_string());
+            }
+        }
+    }
+}
+
+/// Returns how a unit is suppressed, if it is
+///
+/// # Arguments
+///
+/// * `unit` - Unit processed by [`apply_suppressions`]
+/// * `file_ignored` - Whether the file carries an `ignore-file` directive
+///
+/// # Returns
+///
+/// Suppression source or `None`
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::{
+///     analysis::suppression::{ATTRIBUTE_MARKER, unit_suppression},
+///     types::{LineSpan, SemanticUnit, SemanticUnitKind, Suppression, Visibility},
+/// };
+///
+/// let unit = SemanticUnit::new(
+///     SemanticUnitKind::Function,
+///     "dispatch".to_string(),
+///     Visibility::Public,
+///     LineSpan::new(1, 400),
+///     vec![ATTRIBUTE_MARKER.to_string()],
+/// );
+/// assert_eq!(unit_suppression(&unit, false), Some(Suppression::Attribute));
+/// assert_eq!(unit_suppression(&unit, true), Some(Suppression::File));
+/// ```
+pub fn unit_suppression(unit: &SemanticUnit, file_ignored: bool) -> Option<Suppression> {
+    if file_ignored {
+        Some(Suppression::File)
+    } else if unit.has_attribute(ATTRIBUTE_MARKER) {
+        Some(Suppression::Attribute)
+    } else if unit.has_attribute(COMMENT_MARKER) {
+        Some(Suppression::Comment)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::analysis::extractor::extract_semantic_units_from_str;
+
+    fn extract(code: &str) -> Vec<SemanticUnit> {
+        extract_semantic_units_from_str(code, Path::new("src/lib.rs")).expect("parse")
+    }
+
+    fn suppressed(units: &[SemanticUnit]) -> Vec<&str> {
+        units
+            .iter()
+            .filter(|u| unit_suppression(u, false).is_some())
+            .map(|u| u.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_comment_suppresses_only_next_item() {
+        let code = "\
+pub fn before() {}
+
+// rust-diff-analyzer: ignore-next-item -- generated opcode table
+/// Opcode names
+#[inline]
+pub fn opcodes() {}
+
+pub fn after() {}
+";
+        assert_eq!(suppressed(&extract(code)), ["opcodes"]);
+    }
+
+    #[test]
+    fn test_comment_inside_impl_targets_method() {
+        let code = "\
+impl Codec {
+    fn encode() {}
+
+    // rust-diff-analyzer: ignore-next-item
+    fn decode() {}
+}
+";
+        assert_eq!(suppressed(&extract(code)), ["decode"]);
+    }
+
+    #[test]
+    fn test_comment_inside_function_body_is_ignored() {
+        let code = "\
+pub fn dispatch() {
+    // rust-diff-analyzer: ignore-next-item
+    let table = 1;
+}
+
+pub fn unrelated() {}
+";
+        assert!(suppressed(&extract(code)).is_empty());
+    }
+
+    #[test]
+    fn test_comment_requires_adjacent_item() {
+        let code = "\
+// rust-diff-analyzer: ignore-next-item
+const SEPARATOR: () = ();
+
+pub fn after() {}
+";
+        assert_eq!(suppressed(&extract(code)), ["SEPARATOR"]);
+
+        let detached = "\
+// rust-diff-analyzer: ignore-next-item
+
+pub fn after() {}
+";
+        assert!(suppressed(&extract(detached)).is_empty());
+    }
+
+    #[test]
+    fn test_attribute_suppresses_enclosed_units() {
+        let code = "\
+#[cfg_attr(any(), rust_diff_analyzer::skip)]
+mod protocol {
+    pub fn dispatch() {}
+}
+
+pub fn counted() {}
+";
+        let units = extract(code);
+        assert_eq!(suppressed(&units), ["protocol", "dispatch"]);
+        assert_eq!(
+            unit_suppression(&units[1], false),
+            Some(Suppression::Attribute)
+        );
+    }
+
+    #[test]
+    fn test_unknown_directive_is_ignored() {
+        assert_eq!(
+            parse_directive("// rust-diff-analyzer: ignore-everything"),
+            None
+        );
+        assert_eq!(parse_directive("//! rust-diff-analyzer: ignore-file"), None);
+        assert_eq!(
+            parse_directive("let x = 1; // rust-diff-analyzer: ignore-file"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_ignore_file_only_in_leading_comments() {
+        let in_string = "pub const HELP: &str = \"\n// rust-diff-analyzer: ignore-file\n\";\n";
+        assert!(!ignores_file(in_string));
+
+        let in_raw_string =
+            "fn help() -> &'static str {\n    r#\"\n// rust-diff-analyzer: ignore-file\n\"#\n}\n";
+        assert!(!ignores_file(in_raw_string));
+
+        let in_body = "fn run() {\n    // rust-diff-analyzer: ignore-file\n}\n";
+        assert!(!ignores_file(in_body));
+
+        let header = "//! Opcode tables\n\n// rust-diff-analyzer: ignore-file\nfn run() {}\n";
+        assert!(ignores_file(header));
+    }
+}
@@ -0,0 +1,574 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::path::Path;
+
+use masterror::AppError;
+
+use crate::{
+    analysis::mapper::map_changes_with_rules,
+    classifier::{
+        allowlist::{StaleEntries, stale_entries},
+        engine::ClassificationRule,
+        rules::{calculate_weight, check_limits},
+    },
+    config::Config,
+    git::{FileDiff, parse_diff},
+    output::{Formatter, FormatterRegistry},
+    source::SourceProvider,
+    types::{AnalysisResult, Change, CodeType, SemanticUnitKind, Summary},
+    workspace::Workspace,
+};
+
+/// Runs the whole analysis pipeline for one configuration
+///
+/// Parses the diff, maps changed lines to semantic units, aggregates the
+/// summary and evaluates limits, producing the same [`AnalysisResult`] the
+/// command line tool reports.
+#[derive(Debug, Clone)]
+pub struct Analyzer {
+    config: Config,
+    /// Classification rules in effect, built once from `config`
+    rules: Vec<ClassificationRule>,
+    formatters: FormatterRegistry,
+}
+
+impl Default for Analyzer {
+    fn default() -> Self {
+        Self::new(Config::default())
+    }
+}
+
+impl Analyzer {
+    /// Creates an analyzer from a configuration
+    ///
+    /// The configuration is used as is: it is not validated and no
+    /// workspace facts are discovered. Use [`Analyzer::for_repository`] to
+    /// do both.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Configuration
+    ///
+    /// # Returns
+    ///
+    /// A new Analyzer instance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{Analyzer, Config};
+    ///
+    /// let analyzer = Analyzer::new(Config::default());
+    /// assert_eq!(analyzer.config().limits.max_prod_units, 30);
+    /// ```
+    pub fn new(config: Config) -> Self {
+        let rules = config.classification_rules().into_owned();
+        Self {
+            config,
+            rules,
+            formatters: FormatterRegistry::default(),
+        }
+    }
+
+    /// Creates an analyzer for a repository checkout
+    ///
+    /// Validates the configuration and discovers workspace facts (Cargo
+    /// manifests, `.gitattributes`) below `base_dir`.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Configuration
+    /// * `base_dir` - Repository root
+    ///
+    /// # Returns
+    ///
+    /// Analyzer or error
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the configuration is invalid or repository metadata
+    /// cannot be read
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::{Analyzer, Config};
+    ///
+    /// let analyzer =
+    ///     Analyzer::for_repository(Config::default(), Path::new("does-not-exist")).unwrap();
+    /// assert!(analyzer.config().workspace.test_features.is_empty());
+    /// ```
+    pub fn for_repository(mut config: Config, base_dir: &Path) -> Result<Self, AppError> {
+        config.validate()?;
+        config.workspace = Workspace::discover(base_dir, &config)?;
+        Ok(Self::new(config))
+    }
+
+    /// Returns the configuration
+    ///
+    /// # Returns
+    ///
+    /// Configuration the analyzer was built from
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{Analyzer, Config};
+    ///
+    /// let analyzer = Analyzer::new(Config::default());
+    /// assert!(analyzer.config().limits.fail_on_exceed);
+    /// ```
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Registers a custom formatter, selectable through `output.format`
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Formatter name
+    /// * `formatter` - Formatter implementation
+    ///
+    /// # Returns
+    ///
+    /// Analyzer with the formatter registered
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{
+    ///     Analyzer, AppError, Config, config::ConfigBuilder, output::Formatter,
+    ///     source::MemorySource, types::AnalysisResult,
+    /// };
+    ///
+    /// struct Units;
+    ///
+    /// impl Formatter for Units {
+    ///     fn format(&self, result: &AnalysisResult, _config: &Config) -> Result<String, AppError> {
+    ///         Ok(format!("{} units\n", result.changes.len()))
+    ///     }
+    /// }
+    ///
+    /// let config = ConfigBuilder::new().output_format("units").build();
+    /// let analyzer = Analyzer::new(config).with_formatter("units", Units);
+    /// let result = analyzer.analyze_diff("", &MemorySource::new()).unwrap();
+    /// assert_eq!(analyzer.format(&result).unwrap(), "0 units\n");
+    /// ```
+    pub fn with_formatter<F>(mut self, name: impl Into<String>, formatter: F) -> Self
+    where
+        F: Formatter + Send + Sync + 'static,
+    {
+        self.formatters.register(name, formatter);
+        self
+    }
+
+    /// Returns the formatters available to [`Analyzer::format`]
+    ///
+    /// # Returns
+    ///
+    /// Built-in and registered formatters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{Analyzer, Config};
+    ///
+    /// let analyzer = Analyzer::new(Config::default());
+    /// assert!(analyzer.formatters().get("github").is_some());
+    /// ```
+    pub fn formatters(&self) -> &FormatterRegistry {
+        &self.formatters
+    }
+
+    /// Analyzes a unified diff
+    ///
+    /// # Arguments
+    ///
+    /// * `diff` - Unified diff text, as produced by `git diff`
+    /// * `source` - Provider of old and new file contents
+    ///
+    /// # Returns
+    ///
+    /// Analysis result with summary and limit violations
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the diff cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{
+    ///     Analyzer, Config,
+    ///     source::{MemorySource, Revision},
+    /// };
+    ///
+    /// let diff = "\
+    /// diff --git a/src/lib.rs b/src/lib.rs
+    /// --- a/src/lib.rs
+    /// +++ b/src/lib.rs
+    /// @@ -1,0 +1,3 @@
+    /// +pub fn added() {
+    /// +    println!(\"new\");
+    /// +}
+    /// ";
+    ///
+    /// let source = MemorySource::new().with_file(
+    ///     Revision::New,
+    ///     "src/lib.rs",
+    ///     "pub fn added() {\n    println!(\"new\");\n}\n",
+    /// );
+    ///
+    /// let analyzer = Analyzer::new(Config::default());
+    /// let result = analyzer.analyze_diff(diff, &source).unwrap();
+    ///
+    /// assert_eq!(result.summary.prod_functions, 1);
+    /// assert!(!result.summary.exceeds_limit);
+    /// ```
+    pub fn analyze_diff<S>(&self, diff: &str, source: &S) -> Result<AnalysisResult, AppError>
+    where
+        S: SourceProvider + ?Sized,
+    {
+        let diffs = parse_diff(diff)?;
+        self.analyze_file_diffs(&diffs, source)
+    }
+
+    /// Analyzes already parsed file diffs
+    ///
+    /// # Arguments
+    ///
+    /// * `diffs` - Parsed file diffs
+    /// * `source` - Provider of old and new file contents
+    ///
+    /// # Returns
+    ///
+    /// Analysis result with summary and limit violations
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the changes cannot be mapped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{Analyzer, Config, source::MemorySource};
+    ///
+    /// let analyzer = Analyzer::new(Config::default());
+    /// let result = analyzer
+    ///     .analyze_file_diffs(&[], &MemorySource::new())
+    ///     .unwrap();
+    /// assert!(result.changes.is_empty());
+    /// ```
+    pub fn analyze_file_diffs<S>(
+        &self,
+        diffs: &[FileDiff],
+        source: &S,
+    ) -> Result<AnalysisResult, AppError>
+    where
+        S: SourceProvider + ?Sized,
+    {
+        let mut mapped = map_changes_with_rules(diffs, &self.config, &self.rules, source)?;
+        for skipped in &self.config.workspace.skipped_manifests {
+            mapped
+                .scope
+                .add_skipped(skipped.path.clone(), skipped.reason.clone());
+        }
+        let summary = self.summarize(&mapped.changes);
+        let checks = check_limits(&mapped.changes, &summary, &self.config);
+
+        Ok(AnalysisResult::new(mapped.changes, summary, mapped.scope).with_checks(checks))
+    }
+
+    /// Aggregates changes into a summary
+    ///
+    /// Limits are not evaluated, so `status` and `exceeds_limit` keep their
+    /// defaults.
+    ///
+    /// # Arguments
+    ///
+    /// * `changes` - Analyzed changes
+    ///
+    /// # Returns
+    ///
+    /// Summary of the changes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use rust_diff_analyzer::{
+    ///     Analyzer, Config,
+    ///     types::{Change, CodeType, LineSpan, SemanticUnit, SemanticUnitKind, Visibility},
+    /// };
+    ///
+    /// let unit = SemanticUnit::new(
+    ///     SemanticUnitKind::Function,
+    ///     "handler".to_string(),
+    ///     Visibility::Public,
+    ///     LineSpan::new(1, 10),
+    ///     vec![],
+    /// );
+    /// let change = Change::new(
+    ///     PathBuf::from("src/lib.rs"),
+    ///     unit,
+    ///     CodeType::Production,
+    ///     10,
+    ///     0,
+    /// );
+    ///
+    /// let summary = Analyzer::new(Config::default()).summarize(&[change]);
+    /// assert_eq!(summary.prod_functions, 1);
+    /// assert_eq!(summary.weighted_score, 3);
+    /// ```
+    pub fn summarize(&self, changes: &[Change]) -> Summary {
+        let config = &self.config;
+        let mut summary = Summary::default();
+
+        for change in changes {
+            if change.is_suppressed() {
+                summary.suppressed_units += 1;
+                summary.suppressed_lines_added += change.lines_added;
+                summary.suppressed_lines_removed += change.lines_removed;
+                continue;
+            }
+
+            summary
+                .by_type
+                .entry(change.classification)
+                .or_default()
+                .record(change);
+
+            if config.counts_toward_limits(change.classification) {
+                match change.unit.kind {
+                    SemanticUnitKind::Function => summary.prod_functions += 1,
+                    SemanticUnitKind::Struct | SemanticUnitKind::Enum => summary.prod_structs += 1,
+                    _ => summary.prod_other += 1,
+                }
+                summary.prod_lines_added += change.lines_added;
+                summary.prod_lines_removed += change.lines_removed;
+                summary.weighted_score += calculate_weight(&change.unit, config);
+                for feature in change.unit.cfg_features() {
+                    summary
+                        .features
+                        .entry(feature.to_string())
+                        .or_default()
+                        .record(change);
+                }
+            }
+
+            match change.classification {
+                CodeType::Production
+                | CodeType::Generated
+                | CodeType::Fuzz
+                | CodeType::Documentation
+                | CodeType::Vendored => {}
+                CodeType::Test
+                | CodeType::TestUtility
+                | CodeType::Benchmark
+                | CodeType::Example
+                | CodeType::BuildScript => {
+                    summary.test_units += 1;
+                    summary.test_lines_added += change.lines_added;
+                    summary.test_lines_removed += change.lines_removed;
+                }
+            }
+        }
+
+        summary
+    }
+
+    /// Returns allowlist entries that match no unit in the repository
+    ///
+    /// Walks and parses every Rust file below `base_dir`, see
+    /// [`stale_entries`].
+    ///
+    /// # Arguments
+    ///
+    /// * `base_dir` - Repository root
+    ///
+    /// # Returns
+    ///
+    /// Stale entries in configuration order and the paths that could not be
+    /// read or parsed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{Analyzer, Config};
+    ///
+    /// let dir = tempfile::tempdir().unwrap();
+    /// let analyzer = Analyzer::new(Config::default());
+    /// assert!(
+    ///     analyzer
+    ///         .stale_allowlist_entries(dir.path())
+    ///         .entries
+    ///         .is_empty()
+    /// );
+    /// ```
+    pub fn stale_allowlist_entries(&self, base_dir: &Path) -> StaleEntries<'_> {
+        stale_entries(base_dir, &self.config)
+    }
+
+    /// Formats a result in the configured output format
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - Analysis result
+    ///
+    /// # Returns
+    ///
+    /// Formatted output
+    ///
+    /// # Errors
+    ///
+    /// Returns error if no formatter is registered under the configured
+    /// name or formatting fails
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{Analyzer, Config, source::MemorySource};
+    ///
+    /// let analyzer = Analyzer::new(Config::default());
+    /// let result = analyzer.analyze_diff("", &MemorySource::new()).unwrap();
+    /// assert!(
+    ///     analyzer
+    ///         .format(&result)
+    ///         .unwrap()
+    ///         .contains("exceeds_limit=false")
+    /// );
+    /// ```
+    pub fn format(&self, result: &AnalysisResult) -> Result<String, AppError> {
+        self.formatters
+            .format(&self.config.output.format, result, &self.config)
+    }
+
+    /// Formats a result with the formatter registered under a name
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Formatter name, regardless of `output.format`
+    /// * `result` - Analysis result
+    ///
+    /// # Returns
+    ///
+    /// Formatted output
+    ///
+    /// # Errors
+    ///
+    /// Returns error if no formatter is registered under the name or
+    /// formatting fails
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{Analyzer, Config, source::MemorySource};
+    ///
+    /// let analyzer = Analyzer::new(Config::default());
+    /// let result = analyzer.analyze_diff("", &MemorySource::new()).unwrap();
+    /// let json = analyzer.format_as("json", &result).unwrap();
+    /// assert!(json.starts_with('{'));
+    /// ```
+    pub fn format_as(&self, name: &str, result: &AnalysisResult) -> Result<String, AppError> {
+        self.formatters.format(name, result, &self.config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::{
+        classifier::rules::evaluate_limits,
+        source::{MemorySource, Revision},
+        types::{LimitKind, LimitStatus, LineSpan, SemanticUnit, Suppression, Visibility},
+    };
+
+    fn change(kind: SemanticUnitKind, code_type: CodeType, lines: usize) -> Change {
+        Change::new(
+            PathBuf::from("src/lib.rs"),
+            SemanticUnit::new(
+                kind,
+                "unit".to_string(),
+                Visibility::Private,
+                LineSpan::new(1, lines),
+                vec![],
+            ),
+            code_type,
+            lines,
+            0,
+        )
+    }
+
+    #[test]
+    fn test_summarize_splits_counters_by_code_type() {
+        let changes = [
+            change(SemanticUnitKind::Function, CodeType::Production, 4),
+            change(SemanticUnitKind::Struct, CodeType::Production, 2),
+            change(SemanticUnitKind::Function, CodeType::Test, 7),
+            change(SemanticUnitKind::Function, CodeType::Generated, 9),
+            change(SemanticUnitKind::Function, CodeType::Production, 5)
+                .with_suppression(Suppression::File),
+        ];
+
+        let summary = Analyzer::new(Config::default()).summarize(&changes);
+        assert_eq!(summary.total_prod_units(), 2);
+        assert_eq!(summary.prod_lines_added, 6);
+        assert_eq!(summary.test_lines_added, 7);
+        assert_eq!(summary.type_stats(CodeType::Generated).lines_added, 9);
+        assert_eq!(summary.suppressed_units, 1);
+        assert_eq!(summary.type_stats(CodeType::Production).units, 2);
+    }
+
+    #[test]
+    fn test_benchmarks_do_not_count_toward_test_ratio() {
+        let mut config = Config::default();
+        config.limits.min_test_ratio = Some(0.5);
+        let analyzer = Analyzer::new(config);
+
+        let changes = [
+            change(SemanticUnitKind::Function, CodeType::Production, 10),
+            change(SemanticUnitKind::Function, CodeType::Benchmark, 40),
+        ];
+        let summary = analyzer.summarize(&changes);
+        assert_eq!(summary.test_ratio(), Some(0.0));
+
+        let violations = evaluate_limits(&changes, &summary, analyzer.config());
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].limit, LimitKind::MinTestRatio);
+        assert_eq!(violations[0].severity, LimitStatus::Fail);
+    }
+
+    #[test]
+    fn test_analyze_diff_evaluates_limits() {
+        let diff = "\
+diff --git a/src/lib.rs b/src/lib.rs
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,0 +1,2 @@
++fn one() {}
++fn two() {}
+";
+        let mut config = Config::default();
+        config.limits.max_prod_units = 1;
+
+        let source = MemorySource::new().with_file(
+            Revision::New,
+            "src/lib.rs",
+            "fn one() {}\nfn two() {}\n",
+        );
+        let result = Analyzer::new(config)
+            .analyze_diff(diff, &source)
+            .expect("analysis should succeed");
+
+        assert_eq!(result.summary.prod_functions, 2);
+        assert_eq!(result.summary.status, LimitStatus::Fail);
+        assert!(result.summary.exceeds_limit);
+        assert_eq!(result.violations.len(), 1);
+    }
+}
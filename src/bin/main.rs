@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: MIT
 
 use std::{
-    fs,
+    env,
+    fs::{self, OpenOptions},
     io::{self, Read, Write},
     path::{Path, PathBuf},
     process,
@@ -11,15 +12,21 @@ use std::{
 use clap::Parser;
 use masterror::AppError;
 use rust_diff_analyzer::{
-    analysis::map_changes,
-    classifier::rules::{calculate_weight, exceeded_per_type_limits},
-    config::{Config, OutputFormat},
-    error::FileReadError,
-    git::parse_diff,
-    output::format_output,
-    types::{AnalysisResult, SemanticUnitKind, Summary},
+    Analyzer,
+    analysis::explain::{explain_file, format_explanation},
+    config::Config,
+    error::{FileReadError, FileWriteError},
+    output::{format_comment, format_step_outputs},
+    source::{FsSource, GitSource, SourceProvider},
+    types::{AnalysisResult, ExclusionReason, LimitStatus},
 };
 
+/// Exit code when a limit is exceeded
+const EXIT_FAIL: i32 = 1;
+
+/// Exit code when only warning thresholds are crossed
+const EXIT_WARN: i32 = 2;
+
 /// Semantic analyzer for Rust PR diffs
 #[derive(Parser, Debug)]
 #[command(name = "rust-diff-analyzer")]
@@ -33,9 +40,15 @@ struct Args {
     #[arg(short, long)]
     config: Option<PathBuf>,
 
-    /// Output format
-    #[arg(short, long, value_enum)]
-    format: Option<OutputFormatArg>,
+    /// Output format: github, json, human, comment, sarif, junit,
+    /// github-annotations, gitlab or gitlab-comment
+    #[arg(short, long)]
+    format: Option<String>,
+
+    /// Write a format to a path (`-` for stdout) instead of printing the
+    /// configured format; may be repeated, e.g. `--output json=report.json`
+    #[arg(short, long = "output", value_name = "FORMAT=PATH", value_parser = parse_output)]
+    outputs: Vec<Output>,
 
     /// Maximum production units allowed
     #[arg(long)]
@@ -53,17 +66,56 @@ struct Args {
     #[arg(short, long, default_value = ".")]
     base_dir: PathBuf,
 
-    /// Don't exit with code 1 when limits are exceeded
+    /// Git revision the diff was taken against; old file contents are read
+    /// from it to attribute removed lines more precisely
+    #[arg(long)]
+    base_rev: Option<String>,
+
+    /// Don't exit with code 1 when limits are exceeded, or 2 when warning
+    /// thresholds are crossed
     #[arg(long)]
     no_fail: bool,
+
+    /// Also append step outputs to `GITHUB_OUTPUT` and the report to
+    /// `GITHUB_STEP_SUMMARY`, when those variables are set
+    #[arg(long)]
+    github_env: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
-#[derive(clap::ValueEnum, Clone, Debug)]
-enum OutputFormatArg {
-    Github,
-    Json,
-    Human,
-    Comment,
+/// Destination of one rendered format
+#[derive(Clone, Debug)]
+struct Output {
+    format: String,
+    path: Option<PathBuf>,
+}
+
+/// Parses `FORMAT=PATH`, where a path of `-` means stdout
+fn parse_output(value: &str) -> Result<Output, String> {
+    let (format, path) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected FORMAT=PATH, got '{}'", value))?;
+    if format.is_empty() || path.is_empty() {
+        return Err(format!("expected FORMAT=PATH, got '{}'", value));
+    }
+    Ok(Output {
+        format: format.to_string(),
+        path: (path != "-").then(|| PathBuf::from(path)),
+    })
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Show the extracted units of a file and why each is classified as it is
+    Explain {
+        /// File to explain, optionally with a line: `src/lib.rs:42`
+        target: String,
+    },
+    /// List `limits.allowlist` entries that match no unit in the repository;
+    /// exits with code 1 if there are any
+    CheckAllowlist,
 }
 
 fn main() {
@@ -76,6 +128,113 @@ fn main() {
 fn run() -> Result<(), AppError> {
     let args = Args::parse();
 
+    let analyzer = Analyzer::for_repository(load_config(&args)?, &args.base_dir)?;
+    let config = analyzer.config();
+    let outputs = if args.outputs.is_empty() {
+        vec![Output {
+            format: config.output.format.clone(),
+            path: None,
+        }]
+    } else {
+        args.outputs.clone()
+    };
+    for output in &outputs {
+        analyzer.formatters().require(&output.format)?;
+    }
+
+    match &args.command {
+        Some(Command::Explain { target }) => return explain(target, &args.base_dir, config),
+        Some(Command::CheckAllowlist) => return check_allowlist(&analyzer, &args.base_dir),
+        None => {}
+    }
+
+    for skipped in &config.workspace.skipped_manifests {
+        if let ExclusionReason::ReadError(message) | ExclusionReason::ParseError(message) =
+            &skipped.reason
+        {
+            eprintln!(
+                "warning: skipped manifest '{}': {}",
+                skipped.path.display(),
+                message
+            );
+        }
+    }
+
+    let diff_content = read_diff(&args.diff_file)?;
+
+    let source: Box<dyn SourceProvider> = match &args.base_rev {
+        Some(rev) => Box::new(GitSource::new(&args.base_dir, rev)),
+        None => Box::new(FsSource::new(&args.base_dir)),
+    };
+    let result = analyzer.analyze_diff(&diff_content, source.as_ref())?;
+
+    for output in &outputs {
+        let rendered = analyzer.format_as(&output.format, &result)?;
+        match &output.path {
+            Some(path) => fs::write(path, rendered)
+                .map_err(|e| AppError::from(FileWriteError::new(path, e)))?,
+            None => print!("{}", rendered),
+        }
+    }
+    io::stdout()
+        .flush()
+        .map_err(|e| AppError::from(rust_diff_analyzer::error::IoError(e)))?;
+
+    if args.github_env {
+        write_github_env(&result, config);
+    }
+
+    if config.limits.fail_on_exceed && !args.no_fail {
+        match result.summary.status {
+            LimitStatus::Ok => {}
+            LimitStatus::Warn => process::exit(EXIT_WARN),
+            LimitStatus::Fail => process::exit(EXIT_FAIL),
+        }
+    }
+
+    Ok(())
+}
+
+/// Appends step outputs and the markdown report to the files GitHub Actions
+/// names in `GITHUB_OUTPUT` and `GITHUB_STEP_SUMMARY`, when set
+///
+/// The report has already been printed by then, so a failed write only
+/// warns and leaves the exit code to the limit status.
+fn write_github_env(result: &AnalysisResult, config: &Config) {
+    let files = [
+        ("GITHUB_OUTPUT", format_step_outputs(result)),
+        ("GITHUB_STEP_SUMMARY", format_comment(result, config)),
+    ];
+    for (name, content) in files {
+        if let Some(path) = github_env_file(name)
+            && let Err(e) = append(&path, &content)
+        {
+            eprintln!(
+                "warning: could not write {} '{}': {}",
+                name,
+                path.display(),
+                e
+            );
+        }
+    }
+}
+
+fn github_env_file(name: &str) -> Option<PathBuf> {
+    env::var_os(name)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+fn append(path: &Path, content: &str) -> io::Result<()> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+}
+
+/// Loads the configuration file and applies command line overrides
+fn load_config(args: &Args) -> Result<Config, AppError> {
     let mut config = if let Some(config_path) = &args.config {
         Config::from_file(config_path)?
     } else {
@@ -87,13 +246,8 @@ fn run() -> Result<(), AppError> {
         }
     };
 
-    if let Some(format) = args.format {
-        config.output.format = match format {
-            OutputFormatArg::Github => OutputFormat::Github,
-            OutputFormatArg::Json => OutputFormat::Json,
-            OutputFormatArg::Human => OutputFormat::Human,
-            OutputFormatArg::Comment => OutputFormat::Comment,
-        };
+    if let Some(format) = &args.format {
+        config.output.format = format.clone();
     }
 
     if let Some(max_units) = args.max_units {
@@ -108,59 +262,49 @@ fn run() -> Result<(), AppError> {
         config.limits.max_prod_lines = Some(max_lines);
     }
 
-    config.validate()?;
-
-    let diff_content = read_diff(&args.diff_file)?;
+    Ok(config)
+}
 
-    let file_diffs = parse_diff(&diff_content)?;
-
-    let base_dir = args.base_dir.clone();
-    let map_result = map_changes(&file_diffs, &config, |path| {
-        let full_path = base_dir.join(path);
-        fs::read_to_string(full_path)
-    })?;
-    let changes = map_result.changes;
-    let scope = map_result.scope;
-
-    let mut summary = Summary::default();
-
-    for change in &changes {
-        if change.classification.is_production() {
-            match change.unit.kind {
-                SemanticUnitKind::Function => summary.prod_functions += 1,
-                SemanticUnitKind::Struct | SemanticUnitKind::Enum => summary.prod_structs += 1,
-                _ => summary.prod_other += 1,
-            }
-            summary.prod_lines_added += change.lines_added;
-            summary.prod_lines_removed += change.lines_removed;
-            summary.weighted_score += calculate_weight(&change.unit, &config);
-        } else {
-            summary.test_units += 1;
-            summary.test_lines_added += change.lines_added;
-            summary.test_lines_removed += change.lines_removed;
+/// Prints allowlist entries that match no unit, exiting with code 1 if any
+fn check_allowlist(analyzer: &Analyzer, base_dir: &Path) -> Result<(), AppError> {
+    let stale = analyzer.stale_allowlist_entries(base_dir);
+    for skipped in &stale.skipped {
+        if let ExclusionReason::ReadError(message) | ExclusionReason::ParseError(message) =
+            &skipped.reason
+        {
+            eprintln!("warning: skipped '{}': {}", skipped.path.display(), message);
         }
     }
+    for entry in &stale.entries {
+        println!(
+            "limits.allowlist entry '{}' ({}) matches no unit",
+            entry.pattern, entry.reason
+        );
+    }
+    if !stale.entries.is_empty() {
+        process::exit(EXIT_FAIL);
+    }
+    Ok(())
+}
 
-    summary.exceeds_limit = summary.total_prod_units() > config.limits.max_prod_units
-        || summary.weighted_score > config.limits.max_weighted_score
-        || config
-            .limits
-            .max_prod_lines
-            .map(|limit| summary.prod_lines_added > limit)
-            .unwrap_or(false)
-        || !exceeded_per_type_limits(&changes, &config).is_empty();
-
-    let result = AnalysisResult::new(changes, summary, scope);
+/// Prints the classification decision chain for a `file[:line]` target
+fn explain(target: &str, base_dir: &Path, config: &Config) -> Result<(), AppError> {
+    let (file, line) = match target.rsplit_once(':') {
+        Some((file, line)) => match line.parse::<usize>() {
+            Ok(line) => (file, Some(line)),
+            Err(_) => (target, None),
+        },
+        None => (target, None),
+    };
 
-    let output = format_output(&result, &config)?;
-    print!("{}", output);
-    io::stdout()
-        .flush()
-        .map_err(|e| AppError::from(rust_diff_analyzer::error::IoError(e)))?;
+    let path = PathBuf::from(file);
+    let full_path = base_dir.join(&path);
+    let content = fs::read(&full_path)
+        .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+        .map_err(|e| AppError::from(FileReadError::new(full_path, e)))?;
 
-    if result.summary.exceeds_limit && config.limits.fail_on_exceed && !args.no_fail {
-        process::exit(1);
-    }
+    let explanation = explain_file(&content, &path, line, config)?;
+    print!("{}", format_explanation(&path, &explanation));
 
     Ok(())
 }
@@ -1,19 +1,26 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+pub mod allowlist;
 pub mod attr_classifier;
+pub mod engine;
+pub mod header_classifier;
 pub mod path_classifier;
 pub mod rules;
 
 use std::path::Path;
 
+use self::engine::ClassificationRule;
 use crate::{
     config::Config,
-    types::{CodeType, SemanticUnit},
+    types::{ClassificationReason, CodeType, SemanticUnit},
 };
 
 /// Classifies a semantic unit as production or test code
 ///
+/// The configured rules (or the default rule set) are evaluated in order and
+/// the first matching rule decides; units matching no rule are production.
+///
 /// # Arguments
 ///
 /// * `unit` - The semantic unit to classify
@@ -48,37 +55,118 @@ use crate::{
 /// assert!(classification == rust_diff_analyzer::types::CodeType::Test);
 /// ```
 pub fn classify_unit(unit: &SemanticUnit, path: &Path, config: &Config) -> CodeType {
-    if config.is_build_script(path) {
-        return CodeType::BuildScript;
-    }
-
-    if path_classifier::is_example_path(path) {
-        return CodeType::Example;
-    }
-
-    if path_classifier::is_bench_path(path) {
-        return CodeType::Benchmark;
-    }
-
-    if config.is_test_path(path) {
-        return CodeType::Test;
-    }
-
-    if attr_classifier::is_bench_unit(unit) {
-        return CodeType::Benchmark;
-    }
-
-    if attr_classifier::is_test_unit(unit) {
-        return CodeType::Test;
-    }
+    config
+        .classification_rules()
+        .iter()
+        .find(|rule| rule.matches(unit, path, config))
+        .map(|rule| rule.code_type)
+        .unwrap_or(CodeType::Production)
+}
 
-    if attr_classifier::is_in_test_module(unit) {
-        return CodeType::TestUtility;
-    }
+/// Classifies a semantic unit with a prebuilt rule set
+///
+/// Like [`classify_unit_with_reason`], but takes the rules from the caller
+/// so they are built once for many units.
+///
+/// # Arguments
+///
+/// * `unit` - The semantic unit to classify
+/// * `path` - Path to the file containing the unit
+/// * `config` - Configuration the rule predicates read
+/// * `rules` - Rules from [`Config::classification_rules`]
+///
+/// # Returns
+///
+/// Classification of the code and the rule (or default) that produced it
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_diff_analyzer::{
+///     classifier::classify_unit_with_rules,
+///     config::Config,
+///     types::{CodeType, LineSpan, SemanticUnit, SemanticUnitKind, Visibility},
+/// };
+///
+/// let unit = SemanticUnit::new(
+///     SemanticUnitKind::Function,
+///     "bench_parse".to_string(),
+///     Visibility::Private,
+///     LineSpan::new(1, 10),
+///     vec![],
+/// );
+///
+/// let config = Config::default();
+/// let rules = config.classification_rules();
+/// let (code_type, _) =
+///     classify_unit_with_rules(&unit, Path::new("benches/parse.rs"), &config, &rules);
+/// assert_eq!(code_type, CodeType::Benchmark);
+/// ```
+pub fn classify_unit_with_rules(
+    unit: &SemanticUnit,
+    path: &Path,
+    config: &Config,
+    rules: &[ClassificationRule],
+) -> (CodeType, ClassificationReason) {
+    let matched = rules
+        .iter()
+        .enumerate()
+        .find(|(_, rule)| rule.matches(unit, path, config));
 
-    if attr_classifier::has_test_feature(unit, config) {
-        return CodeType::TestUtility;
+    match matched {
+        Some((index, rule)) => (
+            rule.code_type,
+            ClassificationReason::Rule {
+                rule: rule.label(index),
+                matched: rule.describe_match(unit, path, config),
+            },
+        ),
+        None => (CodeType::Production, ClassificationReason::Default),
     }
+}
 
-    CodeType::Production
+/// Classifies a semantic unit and records what decided it
+///
+/// # Arguments
+///
+/// * `unit` - The semantic unit to classify
+/// * `path` - Path to the file containing the unit
+/// * `config` - Configuration
+///
+/// # Returns
+///
+/// Classification of the code and the rule (or default) that produced it
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_diff_analyzer::{
+///     classifier::classify_unit_with_reason,
+///     config::Config,
+///     types::{ClassificationReason, CodeType, LineSpan, SemanticUnit, SemanticUnitKind, Visibility},
+/// };
+///
+/// let unit = SemanticUnit::new(
+///     SemanticUnitKind::Function,
+///     "run".to_string(),
+///     Visibility::Public,
+///     LineSpan::new(1, 10),
+///     vec![],
+/// );
+///
+/// let config = Config::default();
+/// let (code_type, reason) = classify_unit_with_reason(&unit, Path::new("tests/it.rs"), &config);
+/// assert_eq!(code_type, CodeType::Test);
+/// assert!(matches!(reason, ClassificationReason::Rule { rule, .. } if rule == "test-path"));
+/// ```
+pub fn classify_unit_with_reason(
+    unit: &SemanticUnit,
+    path: &Path,
+    config: &Config,
+) -> (CodeType, ClassificationReason) {
+    classify_unit_with_rules(unit, path, config, &config.classification_rules())
 }
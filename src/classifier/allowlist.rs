@@ -0,0 +1,378 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    analysis::{extractor::extract_semantic_units_from_str, mapper::path_exclusion},
+    config::{AllowlistEntry, Config},
+    git::GlobPattern,
+    types::{ExclusionReason, SemanticUnit, SkippedFile},
+};
+
+/// Returns the module path a source file contributes within its crate
+///
+/// Files outside a `src/` directory, `src/lib.rs`, `src/main.rs` and
+/// binaries under `src/bin/` are crate roots and contribute nothing.
+///
+/// # Arguments
+///
+/// * `file` - Repository-relative path of the source file
+///
+/// # Returns
+///
+/// Module names, outermost first
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_diff_analyzer::classifier::allowlist::crate_module_path;
+///
+/// assert_eq!(
+///     crate_module_path(Path::new("crates/core/src/proto/mod.rs")),
+///     ["proto"]
+/// );
+/// assert_eq!(
+///     crate_module_path(Path::new("src/proto/api.rs")),
+///     ["proto", "api"]
+/// );
+/// assert!(crate_module_path(Path::new("src/lib.rs")).is_empty());
+/// ```
+pub fn crate_module_path(file: &Path) -> Vec<String> {
+    let components: Vec<String> = file
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+
+    let Some(src) = components.iter().rposition(|c| c == "src") else {
+        return Vec::new();
+    };
+    let mut modules = &components[src + 1..];
+    if modules.first().is_some_and(|c| c == "bin") {
+        modules = modules.get(2..).unwrap_or_default();
+    }
+
+    let Some((file_name, dirs)) = modules.split_last() else {
+        return Vec::new();
+    };
+    let mut path = dirs.to_vec();
+    let is_root = dirs.is_empty() && (file_name == "lib.rs" || file_name == "main.rs");
+    if !is_root && file_name != "mod.rs" {
+        path.push(file_name.trim_end_matches(".rs").to_string());
+    }
+    path
+}
+
+/// Returns the crate-qualified path of a unit
+///
+/// # Arguments
+///
+/// * `unit` - Semantic unit
+/// * `file` - Repository-relative path of the file containing the unit
+///
+/// # Returns
+///
+/// Path such as `crate::proto::api::Message::encode`
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_diff_analyzer::{
+///     classifier::allowlist::unit_path,
+///     types::{LineSpan, SemanticUnit, SemanticUnitKind, Visibility},
+/// };
+///
+/// let unit = SemanticUnit::with_impl(
+///     SemanticUnitKind::Function,
+///     "default".to_string(),
+///     "Config".to_string(),
+///     Visibility::Private,
+///     LineSpan::new(1, 5),
+///     vec![],
+/// );
+/// assert_eq!(
+///     unit_path(&unit, Path::new("src/config.rs")),
+///     "crate::config::Config::default"
+/// );
+/// ```
+pub fn unit_path(unit: &SemanticUnit, file: &Path) -> String {
+    let mut segments = vec!["crate".to_string()];
+    segments.extend(crate_module_path(file));
+    segments.push(unit.path_in_file());
+    segments.join("::")
+}
+
+/// Checks whether an allowlist pattern matches a crate-qualified unit path
+///
+/// Segments are separated by `::`; `*` matches within one segment and `**`
+/// spans any number of them. Patterns starting with `crate::` are anchored
+/// at the crate root, others match at any depth. A pattern matching a module
+/// or type also covers everything inside it.
+///
+/// # Arguments
+///
+/// * `pattern` - Allowlist pattern
+/// * `path` - Path returned by [`unit_path`]
+///
+/// # Returns
+///
+/// `true` if the pattern covers the unit
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::classifier::allowlist::pattern_matches;
+///
+/// assert!(pattern_matches(
+///     "crate::proto::*",
+///     "crate::proto::api::Message::encode"
+/// ));
+/// assert!(pattern_matches(
+///     "Config::default",
+///     "crate::config::Config::default"
+/// ));
+/// assert!(!pattern_matches("crate::Config", "crate::config::Config"));
+/// ```
+pub fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let glob = match pattern.strip_prefix("crate::") {
+        Some(rest) => format!("/crate/{}", rest.replace("::", "/")),
+        None => format!("**/{}", pattern.replace("::", "/")),
+    };
+    GlobPattern::new(&glob).matches_within(Path::new(&path.replace("::", "/")))
+}
+
+/// Result of checking the allowlist against the repository
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaleEntries<'a> {
+    /// Entries that match no unit, in configuration order
+    pub entries: Vec<&'a AllowlistEntry>,
+    /// Directories and files that could not be read or parsed
+    pub skipped: Vec<SkippedFile>,
+}
+
+/// Returns the allowlist entries that match no unit in the repository
+///
+/// Every Rust file under `base_dir` is scanned, except `target/`
+/// directories at any depth, hidden directories, symbolic links and files
+/// excluded from analysis. This walks and parses the whole repository, so
+/// it is meant for an explicit check rather than every analysis run.
+/// Directories and files that cannot be read or parsed are skipped and
+/// reported.
+///
+/// # Arguments
+///
+/// * `base_dir` - Repository root directory
+/// * `config` - Configuration with the allowlist
+///
+/// # Returns
+///
+/// Stale entries and skipped paths
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_diff_analyzer::{
+///     classifier::allowlist::stale_entries,
+///     config::{AllowlistEntry, Config},
+/// };
+///
+/// let mut config = Config::default();
+/// config.limits.allowlist = vec![AllowlistEntry::new("crate::gone::*", "removed module")];
+///
+/// let dir = tempfile::tempdir().unwrap();
+/// let stale = stale_entries(dir.path(), &config);
+/// assert_eq!(stale.entries[0].pattern, "crate::gone::*");
+/// assert!(stale.skipped.is_empty());
+/// ```
+pub fn stale_entries<'a>(base_dir: &Path, config: &'a Config) -> StaleEntries<'a> {
+    let allowlist = &config.limits.allowlist;
+    let mut skipped = Vec::new();
+    if allowlist.is_empty() {
+        return StaleEntries {
+            entries: Vec::new(),
+            skipped,
+        };
+    }
+
+    let mut matched = vec![false; allowlist.len()];
+    let mut files = Vec::new();
+    collect_rust_files(base_dir, Path::new(""), config, &mut files, &mut skipped);
+
+    for file in files {
+        let content = match fs::read_to_string(base_dir.join(&file)) {
+            Ok(content) => content,
+            Err(e) => {
+                skipped.push(SkippedFile::new(
+                    file,
+                    ExclusionReason::ReadError(e.to_string()),
+                ));
+                continue;
+            }
+        };
+        let units = match extract_semantic_units_from_str(&content, &file) {
+            Ok(units) => units,
+            Err(e) => {
+                skipped.push(SkippedFile::new(
+                    file,
+                    ExclusionReason::ParseError(e.to_string()),
+                ));
+                continue;
+            }
+        };
+
+        for unit in &units {
+            let path = unit_path(unit, &file);
+            for (entry, found) in allowlist.iter().zip(matched.iter_mut()) {
+                if !*found && pattern_matches(&entry.pattern, &path) {
+                    *found = true;
+                }
+            }
+        }
+
+        if matched.iter().all(|found| *found) {
+            break;
+        }
+    }
+
+    let entries = allowlist
+        .iter()
+        .zip(matched)
+        .filter(|(_, found)| !found)
+        .map(|(entry, _)| entry)
+        .collect();
+    StaleEntries { entries, skipped }
+}
+
+fn collect_rust_files(
+    base_dir: &Path,
+    relative: &Path,
+    config: &Config,
+    files: &mut Vec<PathBuf>,
+    skipped: &mut Vec<SkippedFile>,
+) {
+    let entries = match fs::read_dir(base_dir.join(relative)) {
+        Ok(entries) => entries,
+        Err(e) => {
+            skipped.push(SkippedFile::new(
+                relative.to_path_buf(),
+                ExclusionReason::ReadError(e.to_string()),
+            ));
+            return;
+        }
+    };
+
+    let mut children: Vec<(PathBuf, fs::FileType)> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let file_type = entry.file_type().ok()?;
+            Some((relative.join(entry.file_name()), file_type))
+        })
+        .collect();
+    children.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (path, file_type) in children {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        if name.starts_with('.') || file_type.is_symlink() {
+            continue;
+        }
+
+        if file_type.is_dir() {
+            if name != "target" {
+                collect_rust_files(base_dir, &path, config, files, skipped);
+            }
+        } else if path.extension().is_some_and(|ext| ext == "rs")
+            && path_exclusion(&path, config).is_none()
+        {
+            files.push(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary_and_nested_module_paths() {
+        assert!(crate_module_path(Path::new("src/main.rs")).is_empty());
+        assert!(crate_module_path(Path::new("src/bin/tool.rs")).is_empty());
+        assert_eq!(
+            crate_module_path(Path::new("src/bin/tool/args.rs")),
+            ["args"]
+        );
+        assert_eq!(
+            crate_module_path(Path::new("src/net/main.rs")),
+            ["net", "main"]
+        );
+        assert!(crate_module_path(Path::new("tests/integration.rs")).is_empty());
+    }
+
+    #[test]
+    fn test_pattern_globs() {
+        let path = "crate::proto::api::Message::encode";
+        assert!(pattern_matches("crate::proto", path));
+        assert!(pattern_matches("crate::**::encode", path));
+        assert!(pattern_matches("Message::*", path));
+        assert!(pattern_matches("api::Message", path));
+        assert!(!pattern_matches("crate::api", path));
+        assert!(!pattern_matches("Message::decode", path));
+    }
+
+    #[test]
+    fn test_stale_entries_scan_repository() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        fs::create_dir_all(dir.path().join("src/proto")).expect("mkdir");
+        fs::create_dir_all(dir.path().join("target/debug")).expect("mkdir");
+        fs::write(dir.path().join("src/lib.rs"), "pub mod proto;\n").expect("write");
+        fs::write(
+            dir.path().join("src/proto/mod.rs"),
+            "pub struct Table;\nimpl Table {\n    pub fn lookup() {}\n}\n",
+        )
+        .expect("write");
+        fs::write(
+            dir.path().join("target/debug/out.rs"),
+            "pub fn built() {}\n",
+        )
+        .expect("write");
+        fs::create_dir_all(dir.path().join("crates/core/target")).expect("mkdir");
+        fs::write(
+            dir.path().join("crates/core/target/out.rs"),
+            "pub fn nested_built() {}\n",
+        )
+        .expect("write");
+        fs::write(dir.path().join("src/broken.rs"), "pub fn broken(\n").expect("write");
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(dir.path(), dir.path().join("src/proto/cycle"))
+            .expect("symlink");
+
+        let mut config = Config::default();
+        config.limits.allowlist = vec![
+            AllowlistEntry::new("crate::proto::Table::lookup", "opcode table"),
+            AllowlistEntry::new("built", "build output"),
+            AllowlistEntry::new("nested_built", "build output"),
+            AllowlistEntry::new("crate::legacy::*", "removed"),
+        ];
+
+        let result = stale_entries(dir.path(), &config);
+        let stale: Vec<_> = result.entries.iter().map(|e| e.pattern.as_str()).collect();
+        assert_eq!(stale, ["built", "nested_built", "crate::legacy::*"]);
+        assert_eq!(result.skipped.len(), 1);
+        assert_eq!(result.skipped[0].path, PathBuf::from("src/broken.rs"));
+        assert!(matches!(
+            result.skipped[0].reason,
+            ExclusionReason::ParseError(_)
+        ));
+    }
+}
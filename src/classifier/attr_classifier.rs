@@ -103,7 +103,7 @@ pub fn is_in_test_module(unit: &SemanticUnit) -> bool {
 ///
 /// The AST visitor records `#[cfg(feature = "name")]` gates as
 /// `cfg_feature:name` attribute markers; this matches those markers against
-/// the configured test features.
+/// the configured test features and those derived from Cargo manifests.
 ///
 /// # Arguments
 ///
@@ -135,17 +135,41 @@ pub fn is_in_test_module(unit: &SemanticUnit) -> bool {
 /// assert!(has_test_feature(&unit, &config));
 /// ```
 pub fn has_test_feature(unit: &SemanticUnit, config: &Config) -> bool {
-    unit.attributes.iter().any(|attr| {
-        attr.strip_prefix("cfg_feature:")
-            .map(|name| {
-                config
-                    .classification
-                    .test_features
-                    .iter()
-                    .any(|feature| feature == name)
-            })
-            .unwrap_or(false)
-    })
+    unit.cfg_features()
+        .any(|feature| config.is_test_feature(feature))
+}
+
+/// Checks if unit wraps code generated into `OUT_DIR`
+///
+/// # Arguments
+///
+/// * `unit` - Semantic unit to check
+///
+/// # Returns
+///
+/// `true` if unit has the generated_include marker, i.e. it is a module
+/// whose body includes a file from `OUT_DIR`
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::{
+///     classifier::attr_classifier::is_generated_include,
+///     types::{LineSpan, SemanticUnit, SemanticUnitKind, Visibility},
+/// };
+///
+/// let unit = SemanticUnit::new(
+///     SemanticUnitKind::Module,
+///     "proto".to_string(),
+///     Visibility::Public,
+///     LineSpan::new(1, 3),
+///     vec!["generated_include".to_string()],
+/// );
+///
+/// assert!(is_generated_include(&unit));
+/// ```
+pub fn is_generated_include(unit: &SemanticUnit) -> bool {
+    unit.has_attribute("generated_include")
 }
 
 #[cfg(test)]
@@ -194,6 +218,16 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_has_test_feature_matches_workspace_feature() {
+        let mut config = Config::default();
+        let unit = make_unit(vec!["cfg", "cfg_feature:fixtures"]);
+        assert!(!has_test_feature(&unit, &config));
+
+        config.workspace.test_features.push("fixtures".to_string());
+        assert!(has_test_feature(&unit, &config));
+    }
+
     #[test]
     fn test_has_test_feature_ignores_other_features() {
         let config = Config::default();
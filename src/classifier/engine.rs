@@ -0,0 +1,571 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::path::Path;
+
+use regex::Regex;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::path_classifier::{matching_pattern, path_matches};
+use crate::{
+    config::Config,
+    types::{CodeType, SemanticUnit, SemanticUnitKind, Visibility},
+};
+
+/// Configuration-backed condition usable in a rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RulePredicate {
+    /// Path is a build script
+    BuildScript,
+    /// Path matches `classification.test_paths`
+    TestPath,
+    /// Path matches `classification.generated_paths`
+    GeneratedPath,
+    /// Path matches `classification.vendored_paths`
+    VendoredPath,
+    /// Unit is gated by a configured or manifest-derived test feature
+    TestFeature,
+}
+
+impl RulePredicate {
+    fn matches(self, unit: &SemanticUnit, path: &Path, config: &Config) -> bool {
+        match self {
+            Self::BuildScript => config.is_build_script(path),
+            Self::TestPath => config.is_test_path(path),
+            Self::GeneratedPath => config.is_generated_path(path),
+            Self::VendoredPath => config.is_vendored_path(path),
+            Self::TestFeature => unit.cfg_features().any(|f| config.is_test_feature(f)),
+        }
+    }
+
+    fn describe(self, unit: &SemanticUnit, path: &Path, config: &Config) -> String {
+        match self {
+            Self::BuildScript => config
+                .build_script_source(path)
+                .unwrap_or_else(|| "path is a build script".to_string()),
+            Self::TestPath => describe_pattern(
+                "test_paths",
+                matching_pattern(path, &config.classification.test_paths),
+            ),
+            Self::GeneratedPath => describe_pattern(
+                "generated_paths",
+                matching_pattern(path, &config.classification.generated_paths),
+            ),
+            Self::VendoredPath => describe_pattern(
+                "vendored_paths",
+                matching_pattern(path, &config.classification.vendored_paths),
+            ),
+            Self::TestFeature => match unit.cfg_features().find(|f| config.is_test_feature(f)) {
+                Some(feature) => format!("gated by test feature `{}`", feature),
+                None => "gated by a test feature".to_string(),
+            },
+        }
+    }
+}
+
+fn describe_pattern(list: &str, pattern: Option<&str>) -> String {
+    match pattern {
+        Some(pattern) => format!("path matches {} pattern `{}`", list, pattern),
+        None => format!("path matches {}", list),
+    }
+}
+
+/// Regular expression matched against a unit's qualified name
+///
+/// Compiled when the configuration is loaded, so an invalid expression is
+/// reported as a configuration error.
+#[derive(Debug, Clone)]
+pub struct NamePattern(Regex);
+
+impl NamePattern {
+    /// Compiles a name pattern
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - Regular expression
+    ///
+    /// # Returns
+    ///
+    /// Compiled pattern or the regex error
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the expression is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::classifier::engine::NamePattern;
+    ///
+    /// let pattern = NamePattern::new("^(Mock|Fake)").unwrap();
+    /// assert!(pattern.is_match("MockClient::send"));
+    /// assert!(NamePattern::new("(").is_err());
+    /// ```
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Regex::new(pattern).map(Self)
+    }
+
+    /// Checks whether a name matches the pattern
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name to check
+    ///
+    /// # Returns
+    ///
+    /// `true` if the expression matches anywhere in the name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::classifier::engine::NamePattern;
+    ///
+    /// assert!(
+    ///     NamePattern::new("fixture")
+    ///         .unwrap()
+    ///         .is_match("load_fixture")
+    /// );
+    /// ```
+    pub fn is_match(&self, name: &str) -> bool {
+        self.0.is_match(name)
+    }
+}
+
+impl Serialize for NamePattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for NamePattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        Self::new(&pattern).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Single classification rule
+///
+/// All conditions that are set must match; a rule without conditions
+/// matches every unit. Rules are evaluated in order and the first match
+/// decides the code type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassificationRule {
+    /// Identifier shown when explaining a classification
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Path pattern, with the same syntax as `test_paths`
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Semantic unit kind
+    #[serde(default)]
+    pub kind: Option<SemanticUnitKind>,
+    /// Unit visibility
+    #[serde(default)]
+    pub visibility: Option<Visibility>,
+    /// Attribute recorded on the unit, e.g. `test`, `bench` or `cfg_test`
+    #[serde(default)]
+    pub attribute: Option<String>,
+    /// Feature gating the unit via `#[cfg(feature = "...")]`
+    #[serde(default)]
+    pub cfg_feature: Option<String>,
+    /// Regular expression matched against the unit's qualified name
+    #[serde(default)]
+    pub name: Option<NamePattern>,
+    /// Configuration-backed condition
+    #[serde(default)]
+    pub predicate: Option<RulePredicate>,
+    /// Code type assigned when the rule matches
+    pub code_type: CodeType,
+}
+
+impl ClassificationRule {
+    /// Creates a rule without conditions
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Rule identifier
+    /// * `code_type` - Code type assigned on match
+    ///
+    /// # Returns
+    ///
+    /// A new rule matching every unit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{classifier::engine::ClassificationRule, types::CodeType};
+    ///
+    /// let mut rule = ClassificationRule::new("specs", CodeType::Test);
+    /// rule.path = Some("specs/**".to_string());
+    /// assert_eq!(rule.id.as_deref(), Some("specs"));
+    /// ```
+    pub fn new(id: &str, code_type: CodeType) -> Self {
+        Self {
+            id: Some(id.to_string()),
+            path: None,
+            kind: None,
+            visibility: None,
+            attribute: None,
+            cfg_feature: None,
+            name: None,
+            predicate: None,
+            code_type,
+        }
+    }
+
+    /// Checks whether the rule matches a unit
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - Semantic unit
+    /// * `path` - Path of the file containing the unit
+    /// * `config` - Configuration used by predicates
+    ///
+    /// # Returns
+    ///
+    /// `true` if every set condition matches
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::{
+    ///     classifier::engine::ClassificationRule,
+    ///     config::Config,
+    ///     types::{CodeType, LineSpan, SemanticUnit, SemanticUnitKind, Visibility},
+    /// };
+    ///
+    /// let unit = SemanticUnit::new(
+    ///     SemanticUnitKind::Function,
+    ///     "helper".to_string(),
+    ///     Visibility::Private,
+    ///     LineSpan::new(1, 3),
+    ///     vec![],
+    /// );
+    ///
+    /// let mut rule = ClassificationRule::new("private-fixtures", CodeType::TestUtility);
+    /// rule.path = Some("fixtures/**".to_string());
+    /// rule.visibility = Some(Visibility::Private);
+    ///
+    /// let config = Config::default();
+    /// assert!(rule.matches(&unit, Path::new("fixtures/data.rs"), &config));
+    /// assert!(!rule.matches(&unit, Path::new("src/data.rs"), &config));
+    /// ```
+    pub fn matches(&self, unit: &SemanticUnit, path: &Path, config: &Config) -> bool {
+        self.path.as_deref().is_none_or(|p| path_matches(path, p))
+            && self.kind.as_ref().is_none_or(|k| *k == unit.kind)
+            && self
+                .visibility
+                .as_ref()
+                .is_none_or(|v| *v == unit.visibility)
+            && self
+                .attribute
+                .as_deref()
+                .is_none_or(|a| unit.has_attribute(a))
+            && self
+                .cfg_feature
+                .as_deref()
+                .is_none_or(|f| unit.cfg_features().any(|feature| feature == f))
+            && self
+                .name
+                .as_ref()
+                .is_none_or(|n| n.is_match(&unit.qualified_name()))
+            && self.predicate.is_none_or(|p| p.matches(unit, path, config))
+    }
+
+    /// Describes why the rule matches a unit
+    ///
+    /// Only meaningful for a unit the rule [`matches`](Self::matches).
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - Semantic unit
+    /// * `path` - Path of the file containing the unit
+    /// * `config` - Configuration used by predicates
+    ///
+    /// # Returns
+    ///
+    /// Comma-separated list of the matching conditions
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::{
+    ///     classifier::engine::ClassificationRule,
+    ///     config::Config,
+    ///     types::{CodeType, LineSpan, SemanticUnit, SemanticUnitKind, Visibility},
+    /// };
+    ///
+    /// let unit = SemanticUnit::new(
+    ///     SemanticUnitKind::Function,
+    ///     "it_works".to_string(),
+    ///     Visibility::Private,
+    ///     LineSpan::new(1, 3),
+    ///     vec!["test".to_string()],
+    /// );
+    ///
+    /// let mut rule = ClassificationRule::new("test-attribute", CodeType::Test);
+    /// rule.attribute = Some("test".to_string());
+    ///
+    /// let config = Config::default();
+    /// assert_eq!(
+    ///     rule.describe_match(&unit, Path::new("src/lib.rs"), &config),
+    ///     "has attribute `test`"
+    /// );
+    /// ```
+    pub fn describe_match(&self, unit: &SemanticUnit, path: &Path, config: &Config) -> String {
+        let mut parts = Vec::new();
+
+        if let Some(pattern) = &self.path {
+            parts.push(format!("path matches `{}`", pattern));
+        }
+        if let Some(kind) = &self.kind {
+            parts.push(format!("kind is {}", kind.as_str()));
+        }
+        if let Some(visibility) = &self.visibility {
+            parts.push(format!("visibility is {}", visibility.as_str()));
+        }
+        if let Some(attribute) = &self.attribute {
+            parts.push(format!("has attribute `{}`", attribute));
+        }
+        if let Some(feature) = &self.cfg_feature {
+            parts.push(format!("gated by feature `{}`", feature));
+        }
+        if let Some(name) = &self.name {
+            parts.push(format!("name matches /{}/", name.0.as_str()));
+        }
+        if let Some(predicate) = self.predicate {
+            parts.push(predicate.describe(unit, path, config));
+        }
+
+        if parts.is_empty() {
+            "matches every unit".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+
+    /// Returns a label identifying the rule
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position of the rule in its rule set
+    ///
+    /// # Returns
+    ///
+    /// The rule id, or `rule #<index + 1>` for anonymous rules
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{classifier::engine::ClassificationRule, types::CodeType};
+    ///
+    /// let mut rule = ClassificationRule::new("specs", CodeType::Test);
+    /// assert_eq!(rule.label(0), "specs");
+    /// rule.id = None;
+    /// assert_eq!(rule.label(2), "rule #3");
+    /// ```
+    pub fn label(&self, index: usize) -> String {
+        match &self.id {
+            Some(id) => id.clone(),
+            None => format!("rule #{}", index + 1),
+        }
+    }
+}
+
+/// Builds the default rule set
+///
+/// This is the built-in classification cascade: generated and vendored
+/// code, build scripts, fuzz targets, documentation-only modules, examples,
+/// benches, configured test paths, test attributes, naming conventions,
+/// test modules and test features.
+///
+/// # Arguments
+///
+/// * `config` - Configuration providing the naming conventions
+///
+/// # Returns
+///
+/// Ordered default rules
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::{classifier::engine::default_rules, config::Config};
+///
+/// let rules = default_rules(&Config::default());
+/// assert_eq!(rules[0].id.as_deref(), Some("generated-path"));
+/// ```
+pub fn default_rules(config: &Config) -> Vec<ClassificationRule> {
+    let with_predicate = |id: &str, predicate: RulePredicate, code_type: CodeType| {
+        let mut rule = ClassificationRule::new(id, code_type);
+        rule.predicate = Some(predicate);
+        rule
+    };
+    let with_path = |id: &str, path: &str, code_type: CodeType| {
+        let mut rule = ClassificationRule::new(id, code_type);
+        rule.path = Some(path.to_string());
+        rule
+    };
+    let with_attribute = |id: &str, attribute: &str, code_type: CodeType| {
+        let mut rule = ClassificationRule::new(id, code_type);
+        rule.attribute = Some(attribute.to_string());
+        rule
+    };
+
+    let mut rules = vec![
+        with_predicate(
+            "generated-path",
+            RulePredicate::GeneratedPath,
+            CodeType::Generated,
+        ),
+        with_attribute(
+            "generated-include",
+            "generated_include",
+            CodeType::Generated,
+        ),
+        with_predicate(
+            "vendored-path",
+            RulePredicate::VendoredPath,
+            CodeType::Vendored,
+        ),
+        with_predicate(
+            "build-script",
+            RulePredicate::BuildScript,
+            CodeType::BuildScript,
+        ),
+        with_path("fuzz-path", "fuzz/", CodeType::Fuzz),
+        with_attribute("fuzz-target", "fuzz_target", CodeType::Fuzz),
+        with_attribute("documentation", "doc_only", CodeType::Documentation),
+        with_path("example-path", "examples/", CodeType::Example),
+        with_path("bench-path", "benches/", CodeType::Benchmark),
+        with_predicate("test-path", RulePredicate::TestPath, CodeType::Test),
+        with_attribute("bench-attribute", "bench", CodeType::Benchmark),
+        with_attribute("test-attribute", "test", CodeType::Test),
+    ];
+
+    for convention in &config.classification.naming_conventions {
+        rules.push(with_path(
+            &format!("naming-convention:{}", convention.pattern),
+            &convention.pattern,
+            convention.code_type,
+        ));
+    }
+
+    rules.push(with_attribute(
+        "test-module",
+        "cfg_test",
+        CodeType::TestUtility,
+    ));
+    rules.push(with_predicate(
+        "test-feature",
+        RulePredicate::TestFeature,
+        CodeType::TestUtility,
+    ));
+
+    rules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::LineSpan;
+
+    fn unit(name: &str, kind: SemanticUnitKind, attributes: &[&str]) -> SemanticUnit {
+        SemanticUnit::new(
+            kind,
+            name.to_string(),
+            Visibility::Public,
+            LineSpan::new(1, 5),
+            attributes.iter().map(|a| a.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn test_rule_conditions_are_conjunctive() {
+        let config = Config::default();
+        let mut rule = ClassificationRule::new("mock-structs", CodeType::TestUtility);
+        rule.kind = Some(SemanticUnitKind::Struct);
+        rule.name = Some(NamePattern::new("^Mock").expect("valid regex"));
+
+        let path = Path::new("src/client.rs");
+        assert!(rule.matches(
+            &unit("MockClient", SemanticUnitKind::Struct, &[]),
+            path,
+            &config
+        ));
+        assert!(!rule.matches(
+            &unit("MockClient", SemanticUnitKind::Function, &[]),
+            path,
+            &config
+        ));
+        assert!(!rule.matches(
+            &unit("Client", SemanticUnitKind::Struct, &[]),
+            path,
+            &config
+        ));
+    }
+
+    #[test]
+    fn test_cfg_feature_and_predicate_conditions() {
+        let config = Config::default();
+        let gated = unit(
+            "fake",
+            SemanticUnitKind::Function,
+            &["cfg", "cfg_feature:mock"],
+        );
+
+        let mut by_feature = ClassificationRule::new("mock", CodeType::TestUtility);
+        by_feature.cfg_feature = Some("mock".to_string());
+        assert!(by_feature.matches(&gated, Path::new("src/lib.rs"), &config));
+
+        let mut by_predicate = ClassificationRule::new("feature", CodeType::TestUtility);
+        by_predicate.predicate = Some(RulePredicate::TestFeature);
+        assert!(by_predicate.matches(&gated, Path::new("src/lib.rs"), &config));
+        assert!(!by_predicate.matches(
+            &unit("real", SemanticUnitKind::Function, &[]),
+            Path::new("src/lib.rs"),
+            &config
+        ));
+    }
+
+    #[test]
+    fn test_rules_deserialize_from_toml() {
+        let config: Config = toml::from_str(
+            r#"
+            [[classification.rules]]
+            id = "fixtures"
+            path = "fixtures/**"
+            visibility = "Private"
+            code_type = "TestUtility"
+
+            [[classification.rules]]
+            predicate = "test_path"
+            code_type = "Test"
+            "#,
+        )
+        .expect("valid rules");
+
+        let rules = config.classification.rules.expect("rules set");
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].visibility, Some(Visibility::Private));
+        assert_eq!(rules[1].predicate, Some(RulePredicate::TestPath));
+    }
+
+    #[test]
+    fn test_invalid_name_regex_is_config_error() {
+        let result: Result<Config, _> = toml::from_str(
+            r#"
+            [[classification.rules]]
+            name = "("
+            code_type = "Test"
+            "#,
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,129 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+/// Checks whether a source file declares itself generated in its header
+///
+/// Only the leading block of comments, blank lines and inner attributes is
+/// inspected, so a marker mentioned in a doc comment deeper in the file does
+/// not mark the whole file as generated.
+///
+/// # Arguments
+///
+/// * `content` - Source file content
+/// * `markers` - Substrings identifying generated files
+///
+/// # Returns
+///
+/// `true` if a marker appears in the file header
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::classifier::header_classifier::has_generated_header;
+///
+/// let markers = vec!["@generated".to_string()];
+/// assert!(has_generated_header(
+///     "// This file is @generated by prost-build.\npub struct Msg;\n",
+///     &markers
+/// ));
+/// assert!(!has_generated_header(
+///     "pub struct Msg;\n// @generated\n",
+///     &markers
+/// ));
+/// ```
+pub fn has_generated_header(content: &str, markers: &[String]) -> bool {
+    generated_header_marker(content, markers).is_some()
+}
+
+/// Returns the marker that declares a source file generated
+///
+/// # Arguments
+///
+/// * `content` - Source file content
+/// * `markers` - Substrings identifying generated files
+///
+/// # Returns
+///
+/// The first marker found in the file header, or `None`
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::classifier::header_classifier::generated_header_marker;
+///
+/// let markers = vec!["@generated".to_string(), "DO NOT EDIT".to_string()];
+/// assert_eq!(
+///     generated_header_marker("// Code generated by sqlc. DO NOT EDIT.\n", &markers),
+///     Some("DO NOT EDIT")
+/// );
+/// ```
+pub fn generated_header_marker<'a>(content: &str, markers: &'a [String]) -> Option<&'a str> {
+    if markers.is_empty() {
+        return None;
+    }
+
+    let mut in_block_comment = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+
+        let is_header_line = in_block_comment
+            || trimmed.is_empty()
+            || trimmed.starts_with("//")
+            || trimmed.starts_with("/*")
+            || trimmed.starts_with("#!");
+        if !is_header_line {
+            return None;
+        }
+
+        if let Some(marker) = markers.iter().find(|m| trimmed.contains(m.as_str())) {
+            return Some(marker);
+        }
+
+        if trimmed.starts_with("/*") {
+            in_block_comment = true;
+        }
+        if in_block_comment && trimmed.ends_with("*/") {
+            in_block_comment = false;
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Config;
+
+    fn markers() -> Vec<String> {
+        Config::default().classification.generated_markers
+    }
+
+    #[test]
+    fn test_default_markers_detect_common_generators() {
+        let prost = "// This file is @generated by prost-build.\npub struct A {}\n";
+        let go_style = "// Code generated by sqlc. DO NOT EDIT.\n\npub fn q() {}\n";
+        let bindgen =
+            "/* automatically generated by rust-bindgen 0.69.4 */\n\npub type A = u32;\n";
+        let attrs = "#![allow(clippy::all)]\n// @generated\npub struct A;\n";
+
+        for content in [prost, go_style, bindgen, attrs] {
+            assert!(has_generated_header(content, &markers()), "{}", content);
+        }
+    }
+
+    #[test]
+    fn test_marker_after_code_is_ignored() {
+        let content =
+            "pub fn parse() {}\n\n/// Skips files marked DO NOT EDIT\npub fn skip() {}\n";
+        assert!(!has_generated_header(content, &markers()));
+    }
+
+    #[test]
+    fn test_multiline_block_comment_header() {
+        let content = "/*\n * Copyright\n * DO NOT EDIT\n */\npub struct A;\n";
+        assert!(has_generated_header(content, &markers()));
+        assert!(!has_generated_header(content, &[]));
+    }
+}
@@ -3,6 +3,8 @@
 
 use std::path::Path;
 
+use crate::git::GlobPattern;
+
 /// Checks whether a path matches a `/`-separated pattern by whole components
 ///
 /// The pattern's components must appear consecutively in the path. A trailing
@@ -73,6 +75,102 @@ pub fn path_matches_pattern(path: &Path, pattern: &str) -> bool {
     false
 }
 
+/// Checks whether a path matches a single configured path pattern
+///
+/// Patterns containing `*`, `?`, `[` or `\` are gitignore-style globs: a
+/// match on the path or any parent directory counts, and a `/` before the
+/// last character anchors the pattern to the repository root. Plain patterns
+/// keep the component semantics of [`path_matches_pattern`].
+///
+/// # Arguments
+///
+/// * `path` - Path to check
+/// * `pattern` - Pattern without a leading `!`
+///
+/// # Returns
+///
+/// `true` if the pattern matches the path
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_diff_analyzer::classifier::path_classifier::path_matches;
+///
+/// assert!(path_matches(
+///     Path::new("src/api/user_test.rs"),
+///     "**/*_test.rs"
+/// ));
+/// assert!(path_matches(
+///     Path::new("crates/core/fixtures/a/b.rs"),
+///     "crates/*/fixtures/**"
+/// ));
+/// assert!(path_matches(Path::new("crate/tests/it.rs"), "tests/"));
+/// ```
+pub fn path_matches(path: &Path, pattern: &str) -> bool {
+    let glob = GlobPattern::new(pattern);
+    if glob.is_literal() {
+        path_matches_pattern(path, pattern)
+    } else {
+        glob.matches_within(path)
+    }
+}
+
+/// Returns the pattern deciding that a path matches a pattern list
+///
+/// Patterns are evaluated in order and the last matching one wins, as in
+/// `.gitignore`: a pattern prefixed with `!` re-includes paths matched by
+/// earlier patterns.
+///
+/// # Arguments
+///
+/// * `path` - Path to check
+/// * `patterns` - Configured pattern list
+///
+/// # Returns
+///
+/// The last matching positive pattern, or `None` if the path does not match
+/// or was re-included by a negation
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_diff_analyzer::classifier::path_classifier::matching_pattern;
+///
+/// let patterns = vec!["src/gen/**".to_string(), "!src/gen/keep.rs".to_string()];
+/// assert_eq!(
+///     matching_pattern(Path::new("src/gen/api.rs"), &patterns),
+///     Some("src/gen/**")
+/// );
+/// assert_eq!(
+///     matching_pattern(Path::new("src/gen/keep.rs"), &patterns),
+///     None
+/// );
+/// ```
+pub fn matching_pattern<'a>(path: &Path, patterns: &'a [String]) -> Option<&'a str> {
+    let mut matched = None;
+
+    for pattern in patterns {
+        match pattern.strip_prefix('!') {
+            Some(negated) => {
+                if matched.is_some() && path_matches(path, negated) {
+                    matched = None;
+                }
+            }
+            None => {
+                if path_matches(path, pattern) {
+                    matched = Some(pattern.as_str());
+                }
+            }
+        }
+    }
+
+    matched
+}
+
 /// Checks if path is in examples directory
 ///
 /// # Arguments
@@ -190,4 +288,46 @@ mod tests {
         assert!(!path_matches_pattern(Path::new("a/tests"), "tests/"));
         assert!(!path_matches_pattern(Path::new("src/lib.rs"), ""));
     }
+
+    #[test]
+    fn test_glob_patterns() {
+        let mocks = "src/**/mocks/*.rs";
+        assert!(path_matches(Path::new("src/mocks/db.rs"), mocks));
+        assert!(path_matches(Path::new("src/a/b/mocks/db.rs"), mocks));
+        assert!(!path_matches(Path::new("lib/mocks/db.rs"), mocks));
+        assert!(!path_matches(Path::new("src/mocks/inner/db.rs"), mocks));
+
+        assert!(path_matches(
+            Path::new("src/v1_test.rs"),
+            "src/v[0-9]_test.rs"
+        ));
+        assert!(path_matches(Path::new("a/b/x_test.rs"), "*_test.rs"));
+        assert!(!path_matches(Path::new("a/b/x_test.rsx"), "*_test.rs"));
+    }
+
+    #[test]
+    fn test_negation_last_match_wins() {
+        let patterns = vec![
+            "fixtures/".to_string(),
+            "!fixtures/golden/**".to_string(),
+            "fixtures/golden/broken.rs".to_string(),
+        ];
+
+        assert_eq!(
+            matching_pattern(Path::new("fixtures/a.rs"), &patterns),
+            Some("fixtures/")
+        );
+        assert_eq!(
+            matching_pattern(Path::new("fixtures/golden/ok.rs"), &patterns),
+            None
+        );
+        assert_eq!(
+            matching_pattern(Path::new("fixtures/golden/broken.rs"), &patterns),
+            Some("fixtures/golden/broken.rs")
+        );
+        assert_eq!(
+            matching_pattern(Path::new("src/lib.rs"), &["!src/".to_string()]),
+            None
+        );
+    }
 }
@@ -2,8 +2,11 @@
 // SPDX-License-Identifier: MIT
 
 use crate::{
-    config::Config,
-    types::{Change, SemanticUnit, SemanticUnitKind},
+    config::{Config, PerTypeLimits},
+    types::{
+        Change, LimitCheck, LimitKind, LimitScope, LimitStatus, LimitValue, LimitViolation,
+        SemanticUnit, SemanticUnitKind, Summary,
+    },
 };
 
 /// Calculates the weight score for a semantic unit
@@ -65,78 +68,244 @@ pub fn calculate_weight(unit: &SemanticUnit, config: &Config) -> usize {
     }
 }
 
-/// Returns every production unit kind exceeding its per-type limit
+/// Evaluates every limit and warning threshold against the changes
+///
+/// This is the single place limits are checked; the summary status, the
+/// exit code and every formatter derive from the returned violations. A
+/// metric past its limit yields one `Fail` violation and no warning.
+/// Suppressed changes and code types not counted toward limits are
+/// ignored, and `min_test_ratio` applies only once production lines added
+/// exceed `min_test_ratio_above_prod_lines`.
 ///
 /// # Arguments
 ///
 /// * `changes` - Analyzed changes
-/// * `config` - Configuration with optional per-type limits
+/// * `summary` - Summary aggregated from the changes
+/// * `config` - Configuration with limits and warning thresholds
 ///
 /// # Returns
 ///
-/// Tuples of `(kind name, count, limit)` for each exceeded limit; empty when
-/// no per-type limits are configured or none are exceeded
+/// Violations in limit order: units, weighted score, lines, per type, ratio
 ///
 /// # Examples
 ///
 /// ```
-/// use rust_diff_analyzer::{classifier::rules::exceeded_per_type_limits, config::Config};
+/// use rust_diff_analyzer::{
+///     classifier::rules::evaluate_limits,
+///     config::Config,
+///     types::{LimitKind, LimitStatus, Summary},
+/// };
 ///
-/// let config = Config::default();
-/// assert!(exceeded_per_type_limits(&[], &config).is_empty());
+/// let mut config = Config::default();
+/// config.limits.warn.max_weighted_score = Some(50);
+///
+/// let summary = Summary {
+///     weighted_score: 60,
+///     ..Default::default()
+/// };
+/// let violations = evaluate_limits(&[], &summary, &config);
+/// assert_eq!(violations[0].limit, LimitKind::MaxWeightedScore);
+/// assert_eq!(violations[0].severity, LimitStatus::Warn);
 /// ```
-pub fn exceeded_per_type_limits(
+pub fn evaluate_limits(
     changes: &[Change],
+    summary: &Summary,
     config: &Config,
-) -> Vec<(&'static str, usize, usize)> {
-    let per_type = match &config.limits.per_type {
-        Some(limits) => limits,
-        None => return Vec::new(),
-    };
-
-    let kinds = [
-        SemanticUnitKind::Function,
-        SemanticUnitKind::Struct,
-        SemanticUnitKind::Enum,
-        SemanticUnitKind::Trait,
-        SemanticUnitKind::Impl,
-        SemanticUnitKind::Const,
-        SemanticUnitKind::Static,
-        SemanticUnitKind::TypeAlias,
-        SemanticUnitKind::Macro,
-        SemanticUnitKind::Module,
-    ];
-    let limits = [
-        per_type.functions,
-        per_type.structs,
-        per_type.enums,
-        per_type.traits,
-        per_type.impl_blocks,
-        per_type.consts,
-        per_type.statics,
-        per_type.type_aliases,
-        per_type.macros,
-        per_type.modules,
+) -> Vec<LimitViolation> {
+    check_limits(changes, summary, config)
+        .iter()
+        .filter_map(LimitCheck::violation)
+        .collect()
+}
+
+/// Checks whether a change counts toward the value of a violated limit
+///
+/// Suppressed changes and code types excluded from limits never count;
+/// per-type violations only count units of their kind.
+///
+/// # Arguments
+///
+/// * `change` - Analyzed change
+/// * `violation` - Crossed limit
+/// * `config` - Configuration deciding which code types count
+///
+/// # Returns
+///
+/// `true` if the change is part of the measured value
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use rust_diff_analyzer::{
+///     classifier::rules::contributes_to,
+///     config::Config,
+///     types::{
+///         Change, CodeType, LimitKind, LimitScope, LimitStatus, LimitViolation, LineSpan,
+///         SemanticUnit, SemanticUnitKind, Visibility,
+///     },
+/// };
+///
+/// let change = Change::new(
+///     PathBuf::from("src/lib.rs"),
+///     SemanticUnit::new(
+///         SemanticUnitKind::Function,
+///         "handler".to_string(),
+///         Visibility::Public,
+///         LineSpan::new(1, 5),
+///         vec![],
+///     ),
+///     CodeType::Production,
+///     5,
+///     0,
+/// );
+/// let violation = LimitViolation::new(
+///     LimitKind::PerType,
+///     2.0,
+///     1.0,
+///     LimitScope::UnitKind(SemanticUnitKind::Struct),
+///     LimitStatus::Fail,
+/// );
+/// assert!(!contributes_to(&change, &violation, &Config::default()));
+/// ```
+pub fn contributes_to(change: &Change, violation: &LimitViolation, config: &Config) -> bool {
+    !change.is_suppressed()
+        && config.counts_toward_limits(change.classification)
+        && match &violation.scope {
+            LimitScope::Total => true,
+            LimitScope::UnitKind(kind) => change.unit.kind == *kind,
+        }
+}
+
+/// Measures every configured limit and warning threshold
+///
+/// Returns one check per limit that has a failing limit or a warning
+/// threshold configured, whether it passes or not; see [`evaluate_limits`]
+/// for how changes are counted.
+///
+/// # Arguments
+///
+/// * `changes` - Analyzed changes
+/// * `summary` - Summary aggregated from the changes
+/// * `config` - Configuration with limits and warning thresholds
+///
+/// # Returns
+///
+/// Checks in limit order: units, weighted score, lines, per type, ratio
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::{
+///     classifier::rules::check_limits,
+///     config::Config,
+///     types::{LimitStatus, Summary},
+/// };
+///
+/// let checks = check_limits(&[], &Summary::default(), &Config::default());
+/// let names: Vec<_> = checks.iter().map(|c| c.name()).collect();
+/// assert_eq!(names, ["max_prod_units", "max_weighted_score"]);
+/// assert!(checks.iter().all(|c| c.status == LimitStatus::Ok));
+/// ```
+pub fn check_limits(changes: &[Change], summary: &Summary, config: &Config) -> Vec<LimitCheck> {
+    let limits = &config.limits;
+    let warn = &limits.warn;
+    let mut checks = Vec::new();
+
+    let maximums = [
+        (
+            LimitKind::MaxProdUnits,
+            summary.total_prod_units(),
+            warn.max_prod_units,
+            Some(limits.max_prod_units),
+        ),
+        (
+            LimitKind::MaxWeightedScore,
+            summary.weighted_score,
+            warn.max_weighted_score,
+            Some(limits.max_weighted_score),
+        ),
+        (
+            LimitKind::MaxProdLines,
+            summary.prod_lines_added,
+            warn.max_prod_lines,
+            limits.max_prod_lines,
+        ),
     ];
+    for (limit, value, warn, fail) in maximums {
+        checks.extend(check_max(limit, LimitScope::Total, value, warn, fail));
+    }
 
-    let mut counts = [0usize; 10];
-    for change in changes {
-        if !change.classification.is_production() {
+    let no_limits = PerTypeLimits::default();
+    let fail_per_type = limits.per_type.as_ref().unwrap_or(&no_limits).by_kind();
+    let warn_per_type = warn.per_type.as_ref().unwrap_or(&no_limits).by_kind();
+    for ((kind, fail), (_, warn)) in fail_per_type.into_iter().zip(warn_per_type) {
+        if fail.is_none() && warn.is_none() {
             continue;
         }
-        if let Some(index) = kinds.iter().position(|k| *k == change.unit.kind) {
-            counts[index] += 1;
-        }
+        let count = changes
+            .iter()
+            .filter(|change| {
+                !change.is_suppressed()
+                    && config.counts_toward_limits(change.classification)
+                    && change.unit.kind == kind
+            })
+            .count();
+        checks.extend(check_max(
+            LimitKind::PerType,
+            LimitScope::UnitKind(kind),
+            count,
+            warn,
+            fail,
+        ));
     }
 
-    kinds
-        .iter()
-        .zip(limits)
-        .zip(counts)
-        .filter_map(|((kind, limit), count)| {
-            limit.and_then(|limit| (count > limit).then_some((kind.as_str(), count, limit)))
-        })
-        .collect()
+    if limits.min_test_ratio.is_some() || warn.min_test_ratio.is_some() {
+        let ratio = summary
+            .test_ratio()
+            .filter(|_| summary.prod_lines_added > limits.min_test_ratio_above_prod_lines);
+        let below = |minimum: Option<f64>| {
+            minimum.is_some_and(|minimum| ratio.is_some_and(|ratio| ratio < minimum))
+        };
+        let status = if below(limits.min_test_ratio) {
+            LimitStatus::Fail
+        } else if below(warn.min_test_ratio) {
+            LimitStatus::Warn
+        } else {
+            LimitStatus::Ok
+        };
+        checks.push(LimitCheck {
+            limit: LimitKind::MinTestRatio,
+            scope: LimitScope::Total,
+            actual: ratio.map(LimitValue::from),
+            threshold: limits.min_test_ratio.map(LimitValue::from),
+            warn: warn.min_test_ratio.map(LimitValue::from),
+            status,
+        });
+    }
+
+    checks
+}
+
+fn check_max(
+    limit: LimitKind,
+    scope: LimitScope,
+    value: usize,
+    warn: Option<usize>,
+    fail: Option<usize>,
+) -> Option<LimitCheck> {
+    if warn.is_none() && fail.is_none() {
+        return None;
+    }
+    Some(LimitCheck {
+        limit,
+        scope,
+        actual: Some((value as f64).into()),
+        threshold: fail.map(|fail| (fail as f64).into()),
+        warn: warn.map(|warn| (warn as f64).into()),
+        status: LimitStatus::of_max(value, warn, fail),
+    })
 }
 
 #[cfg(test)]
@@ -144,6 +313,59 @@ mod tests {
     use super::*;
     use crate::types::{LineSpan, Visibility};
 
+    fn severities(violations: &[LimitViolation]) -> Vec<(LimitKind, LimitStatus)> {
+        violations.iter().map(|v| (v.limit, v.severity)).collect()
+    }
+
+    #[test]
+    fn test_ratio_limit_disabled_by_default() {
+        let summary = Summary {
+            prod_lines_added: 500,
+            ..Default::default()
+        };
+        assert!(evaluate_limits(&[], &summary, &Config::default()).is_empty());
+
+        let mut config = Config::default();
+        config.limits.min_test_ratio = Some(0.5);
+        let violations = evaluate_limits(&[], &summary, &config);
+        assert_eq!(violations[0].actual, 0.0);
+        assert_eq!(violations[0].threshold, 0.5);
+        assert!(evaluate_limits(&[], &Summary::default(), &config).is_empty());
+
+        config.limits.min_test_ratio_above_prod_lines = 500;
+        assert!(evaluate_limits(&[], &summary, &config).is_empty());
+    }
+
+    #[test]
+    fn test_fail_replaces_warning_for_same_limit() {
+        let mut config = Config::default();
+        config.limits.warn.max_prod_lines = Some(100);
+        config.limits.max_weighted_score = 10;
+
+        let summary = Summary {
+            prod_lines_added: 150,
+            weighted_score: 5,
+            ..Default::default()
+        };
+        assert_eq!(
+            severities(&evaluate_limits(&[], &summary, &config)),
+            [(LimitKind::MaxProdLines, LimitStatus::Warn)]
+        );
+
+        config.limits.max_prod_lines = Some(120);
+        let summary = Summary {
+            weighted_score: 11,
+            ..summary
+        };
+        assert_eq!(
+            severities(&evaluate_limits(&[], &summary, &config)),
+            [
+                (LimitKind::MaxWeightedScore, LimitStatus::Fail),
+                (LimitKind::MaxProdLines, LimitStatus::Fail)
+            ]
+        );
+    }
+
     #[test]
     fn test_weight_calculation() {
         let config = Config::default();
@@ -177,7 +399,7 @@ mod tests {
     }
 
     #[test]
-    fn test_exceeded_per_type_limits() {
+    fn test_per_type_limits() {
         use std::path::PathBuf;
 
         use crate::{
@@ -213,10 +435,24 @@ mod tests {
             make_change(SemanticUnitKind::Struct),
         ];
 
-        let exceeded = exceeded_per_type_limits(&changes, &config);
-        assert_eq!(exceeded, vec![("function", 2, 1)]);
+        let summary = Summary::default();
+        let violations = evaluate_limits(&changes, &summary, &config);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].scope,
+            LimitScope::UnitKind(SemanticUnitKind::Function)
+        );
+        assert_eq!(violations[0].actual, 2.0);
+        assert_eq!(violations[0].threshold, 1.0);
 
         config.limits.per_type = None;
-        assert!(exceeded_per_type_limits(&changes, &config).is_empty());
+        config.limits.warn.per_type = Some(PerTypeLimits {
+            structs: Some(0),
+            ..PerTypeLimits::default()
+        });
+        assert_eq!(
+            severities(&evaluate_limits(&changes, &summary, &config)),
+            [(LimitKind::PerType, LimitStatus::Warn)]
+        );
     }
 }
@@ -1,25 +1,85 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
-use std::{collections::HashSet, fs, path::Path};
+use std::{borrow::Cow, collections::HashSet, fs, path::Path};
 
 use masterror::AppError;
 use serde::{Deserialize, Serialize};
 
-use crate::error::{ConfigError, ConfigValidationError, FileReadError};
+use crate::{
+    classifier::{
+        allowlist,
+        engine::{ClassificationRule, default_rules},
+        path_classifier,
+    },
+    error::{ConfigError, ConfigValidationError, FileReadError},
+    types::{CodeType, SemanticUnit, SemanticUnitKind},
+    workspace::Workspace,
+};
 
 /// Classification configuration
+///
+/// Path lists (`test_paths`, `ignore_paths`, `generated_paths`) accept
+/// gitignore-style globs with `**`, `*`, `?`, character classes and `!`
+/// negation; the last matching pattern wins. Patterns without glob syntax
+/// match consecutive path components anywhere in the path.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClassificationConfig {
     /// Features that indicate test code
     #[serde(default = "default_test_features")]
     pub test_features: Vec<String>,
+    /// Whether to derive additional test features from Cargo manifests
+    ///
+    /// Features reachable only from `[dev-dependencies]` declarations (or
+    /// from other such features) are treated like `test_features`.
+    #[serde(default = "default_detect_test_features")]
+    pub detect_test_features: bool,
+    /// Whether to derive build-script code from Cargo manifests
+    ///
+    /// Files reachable through `mod` declarations from each package's build
+    /// script (`package.build`, `build.rs` by default) and packages used only
+    /// as `[build-dependencies]` are classified as build-script code.
+    #[serde(default = "default_detect_build_scripts")]
+    pub detect_build_scripts: bool,
     /// Paths that contain test code
     #[serde(default = "default_test_paths")]
     pub test_paths: Vec<String>,
+    /// Ordered classification rules replacing the built-in cascade
+    ///
+    /// When unset, [`default_rules`](crate::classifier::engine::default_rules)
+    /// applies. The first matching rule decides the code type; units
+    /// matching no rule are production code.
+    #[serde(default)]
+    pub rules: Option<Vec<ClassificationRule>>,
+    /// Filename and directory conventions identifying test code
+    ///
+    /// The first matching convention decides the code type. Setting this
+    /// list replaces the built-in conventions.
+    #[serde(default = "default_naming_conventions")]
+    pub naming_conventions: Vec<NamingConvention>,
     /// Paths to ignore completely
     #[serde(default)]
     pub ignore_paths: Vec<String>,
+    /// Whether to honor exclusion attributes from `.gitattributes`
+    ///
+    /// Files marked `linguist-generated`, `linguist-vendored` or
+    /// `rust-diff-analyzer=ignore` are skipped.
+    #[serde(default = "default_use_gitattributes")]
+    pub use_gitattributes: bool,
+    /// Paths that contain generated code
+    #[serde(default)]
+    pub generated_paths: Vec<String>,
+    /// Header markers identifying generated files
+    ///
+    /// A file is generated when one of these substrings appears in its
+    /// leading comment block, e.g. `@generated` or `DO NOT EDIT`.
+    #[serde(default = "default_generated_markers")]
+    pub generated_markers: Vec<String>,
+    /// Paths that contain vendored third-party code
+    ///
+    /// Uses the same glob syntax as the other path lists. Empty by default.
+    #[serde(default)]
+    pub vendored_paths: Vec<String>,
     /// Authors to ignore when analyzing changes
     ///
     /// Changes from these authors will be excluded from the analysis.
@@ -39,8 +99,16 @@ impl Default for ClassificationConfig {
     fn default() -> Self {
         Self {
             test_features: default_test_features(),
+            detect_test_features: default_detect_test_features(),
+            detect_build_scripts: default_detect_build_scripts(),
             test_paths: default_test_paths(),
+            rules: None,
+            naming_conventions: default_naming_conventions(),
             ignore_paths: Vec::new(),
+            use_gitattributes: default_use_gitattributes(),
+            generated_paths: Vec::new(),
+            generated_markers: default_generated_markers(),
+            vendored_paths: Vec::new(),
             ignored_authors: Vec::new(),
         }
     }
@@ -54,6 +122,76 @@ fn default_test_features() -> Vec<String> {
     ]
 }
 
+fn default_detect_test_features() -> bool {
+    true
+}
+
+fn default_detect_build_scripts() -> bool {
+    true
+}
+
+/// Path naming convention mapped to a test code type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NamingConvention {
+    /// Path pattern, with the same syntax as `test_paths`
+    pub pattern: String,
+    /// Code type assigned to units in matching files
+    pub code_type: CodeType,
+}
+
+impl NamingConvention {
+    /// Creates a new naming convention
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - Path pattern
+    /// * `code_type` - Code type for matching files
+    ///
+    /// # Returns
+    ///
+    /// A new NamingConvention instance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{config::NamingConvention, types::CodeType};
+    ///
+    /// let convention = NamingConvention::new("**/*_spec.rs", CodeType::Test);
+    /// assert_eq!(convention.code_type, CodeType::Test);
+    /// ```
+    pub fn new(pattern: &str, code_type: CodeType) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            code_type,
+        }
+    }
+}
+
+fn default_naming_conventions() -> Vec<NamingConvention> {
+    vec![
+        NamingConvention::new("*_test.rs", CodeType::Test),
+        NamingConvention::new("*_tests.rs", CodeType::Test),
+        NamingConvention::new("test_utils.rs", CodeType::TestUtility),
+        NamingConvention::new("test_utils/", CodeType::TestUtility),
+        NamingConvention::new("testing.rs", CodeType::TestUtility),
+        NamingConvention::new("testing/", CodeType::TestUtility),
+        NamingConvention::new("mocks.rs", CodeType::TestUtility),
+        NamingConvention::new("mocks/", CodeType::TestUtility),
+    ]
+}
+
+fn default_use_gitattributes() -> bool {
+    true
+}
+
+fn default_generated_markers() -> Vec<String> {
+    vec![
+        "@generated".to_string(),
+        "DO NOT EDIT".to_string(),
+        "automatically generated by rust-bindgen".to_string(),
+    ]
+}
+
 fn default_test_paths() -> Vec<String> {
     vec![
         "tests/".to_string(),
@@ -130,6 +268,44 @@ fn default_const_weight() -> usize {
     1
 }
 
+/// Unit excluded from limits by configuration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllowlistEntry {
+    /// Qualified unit name or glob, e.g. `crate::proto::*` or
+    /// `Config::default`
+    pub pattern: String,
+    /// Why the units are exempt, echoed in the outputs
+    pub reason: String,
+}
+
+impl AllowlistEntry {
+    /// Creates a new allowlist entry
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - Qualified unit name or glob
+    /// * `reason` - Why matching units are exempt
+    ///
+    /// # Returns
+    ///
+    /// A new AllowlistEntry instance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::config::AllowlistEntry;
+    ///
+    /// let entry = AllowlistEntry::new("crate::proto::*", "generated protocol tables");
+    /// assert_eq!(entry.reason, "generated protocol tables");
+    /// ```
+    pub fn new(pattern: &str, reason: &str) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
 /// Per-type limit configuration
 ///
 /// All fields are optional. When set, the analyzer will check that the number
@@ -158,6 +334,67 @@ pub struct PerTypeLimits {
     pub modules: Option<usize>,
 }
 
+impl PerTypeLimits {
+    /// Returns the limit for each unit kind
+    ///
+    /// # Returns
+    ///
+    /// Every unit kind paired with its optional limit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{config::PerTypeLimits, types::SemanticUnitKind};
+    ///
+    /// let limits = PerTypeLimits {
+    ///     traits: Some(2),
+    ///     ..PerTypeLimits::default()
+    /// };
+    /// assert!(
+    ///     limits
+    ///         .by_kind()
+    ///         .contains(&(SemanticUnitKind::Trait, Some(2)))
+    /// );
+    /// ```
+    pub fn by_kind(&self) -> [(SemanticUnitKind, Option<usize>); 10] {
+        [
+            (SemanticUnitKind::Function, self.functions),
+            (SemanticUnitKind::Struct, self.structs),
+            (SemanticUnitKind::Enum, self.enums),
+            (SemanticUnitKind::Trait, self.traits),
+            (SemanticUnitKind::Impl, self.impl_blocks),
+            (SemanticUnitKind::Const, self.consts),
+            (SemanticUnitKind::Static, self.statics),
+            (SemanticUnitKind::TypeAlias, self.type_aliases),
+            (SemanticUnitKind::Macro, self.macros),
+            (SemanticUnitKind::Module, self.modules),
+        ]
+    }
+}
+
+/// Warning thresholds reported before the failing limits are reached
+///
+/// Each field mirrors the limit of the same name in [`LimitsConfig`]. A PR
+/// past a warning threshold but within every limit gets the `warn` status.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WarnLimits {
+    /// Production units above which to warn
+    #[serde(default)]
+    pub max_prod_units: Option<usize>,
+    /// Weighted score above which to warn
+    #[serde(default)]
+    pub max_weighted_score: Option<usize>,
+    /// Production lines added above which to warn
+    #[serde(default)]
+    pub max_prod_lines: Option<usize>,
+    /// Test ratio below which to warn
+    #[serde(default)]
+    pub min_test_ratio: Option<f64>,
+    /// Per-type counts above which to warn
+    #[serde(default)]
+    pub per_type: Option<PerTypeLimits>,
+}
+
 /// Limit configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LimitsConfig {
@@ -170,12 +407,27 @@ pub struct LimitsConfig {
     /// Maximum number of production lines added
     #[serde(default)]
     pub max_prod_lines: Option<usize>,
+    /// Minimum ratio of test lines added to production lines added
+    #[serde(default)]
+    pub min_test_ratio: Option<f64>,
+    /// Production lines added up to which `min_test_ratio` is not enforced
+    #[serde(default)]
+    pub min_test_ratio_above_prod_lines: usize,
     /// Per-type limits for fine-grained control
     #[serde(default)]
     pub per_type: Option<PerTypeLimits>,
+    /// Whether generated code counts toward limits like production code
+    #[serde(default)]
+    pub count_generated: bool,
     /// Whether to fail when limits are exceeded
     #[serde(default = "default_fail_on_exceed")]
     pub fail_on_exceed: bool,
+    /// Units excluded from limits, each with a reason
+    #[serde(default)]
+    pub allowlist: Vec<AllowlistEntry>,
+    /// Warning thresholds for the limits above
+    #[serde(default)]
+    pub warn: WarnLimits,
 }
 
 impl Default for LimitsConfig {
@@ -184,8 +436,13 @@ impl Default for LimitsConfig {
             max_prod_units: default_max_prod_units(),
             max_weighted_score: default_max_weighted_score(),
             max_prod_lines: None,
+            min_test_ratio: None,
+            min_test_ratio_above_prod_lines: 0,
             per_type: None,
+            count_generated: false,
             fail_on_exceed: default_fail_on_exceed(),
+            allowlist: Vec::new(),
+            warn: WarnLimits::default(),
         }
     }
 }
@@ -202,7 +459,11 @@ fn default_fail_on_exceed() -> bool {
     true
 }
 
-/// Output format
+/// Built-in output formats
+///
+/// `output.format` holds a formatter name, so formats registered in a
+/// [`FormatterRegistry`](crate::output::FormatterRegistry) can be selected
+/// as well; this enum names the ones that are always available.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum OutputFormat {
@@ -215,14 +476,61 @@ pub enum OutputFormat {
     Human,
     /// Markdown comment format for PR comments
     Comment,
+    /// SARIF 2.1.0 for code scanning
+    Sarif,
+    /// JUnit XML report with one testcase per limit
+    Junit,
+    /// GitHub workflow command annotations
+    #[serde(rename = "github-annotations")]
+    GithubAnnotations,
+    /// GitLab Code Quality report
+    Gitlab,
+    /// Markdown comment format for GitLab merge request notes
+    #[serde(rename = "gitlab-comment")]
+    GitlabComment,
+}
+
+impl OutputFormat {
+    /// Returns the formatter name of the format
+    ///
+    /// # Returns
+    ///
+    /// Name the format is registered under
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::config::OutputFormat;
+    ///
+    /// assert_eq!(OutputFormat::Comment.as_str(), "comment");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Github => "github",
+            Self::Json => "json",
+            Self::Human => "human",
+            Self::Comment => "comment",
+            Self::Sarif => "sarif",
+            Self::Junit => "junit",
+            Self::GithubAnnotations => "github-annotations",
+            Self::Gitlab => "gitlab",
+            Self::GitlabComment => "gitlab-comment",
+        }
+    }
+}
+
+impl From<OutputFormat> for String {
+    fn from(format: OutputFormat) -> Self {
+        format.as_str().to_string()
+    }
 }
 
 /// Output configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutputConfig {
-    /// Output format to use
-    #[serde(default)]
-    pub format: OutputFormat,
+    /// Name of the formatter to use
+    #[serde(default = "default_output_format")]
+    pub format: String,
     /// Whether to include detailed change information
     #[serde(default = "default_include_details")]
     pub include_details: bool,
@@ -231,12 +539,16 @@ pub struct OutputConfig {
 impl Default for OutputConfig {
     fn default() -> Self {
         Self {
-            format: OutputFormat::default(),
+            format: default_output_format(),
             include_details: default_include_details(),
         }
     }
 }
 
+fn default_output_format() -> String {
+    OutputFormat::default().into()
+}
+
 fn default_include_details() -> bool {
     true
 }
@@ -256,6 +568,9 @@ pub struct Config {
     /// Output settings
     #[serde(default)]
     pub output: OutputConfig,
+    /// Repository facts discovered at runtime, never read from the file
+    #[serde(skip)]
+    pub workspace: Workspace,
 }
 
 impl Config {
@@ -324,6 +639,156 @@ impl Config {
             .into());
         }
 
+        let ratios = [
+            ("limits.min_test_ratio", self.limits.min_test_ratio),
+            (
+                "limits.warn.min_test_ratio",
+                self.limits.warn.min_test_ratio,
+            ),
+        ];
+        for (field, ratio) in ratios {
+            if let Some(ratio) = ratio
+                && !(ratio.is_finite() && ratio >= 0.0)
+            {
+                return Err(ConfigValidationError {
+                    field: field.to_string(),
+                    message: format!("must be a non-negative number, got {}", ratio),
+                }
+                .into());
+            }
+        }
+
+        let warn = &self.limits.warn;
+        let mut maximums = vec![
+            (
+                "max_prod_units".to_string(),
+                warn.max_prod_units,
+                Some(self.limits.max_prod_units),
+            ),
+            (
+                "max_weighted_score".to_string(),
+                warn.max_weighted_score,
+                Some(self.limits.max_weighted_score),
+            ),
+            (
+                "max_prod_lines".to_string(),
+                warn.max_prod_lines,
+                self.limits.max_prod_lines,
+            ),
+        ];
+        if let (Some(warn_per_type), Some(per_type)) = (&warn.per_type, &self.limits.per_type) {
+            for ((kind, warn), (_, limit)) in
+                warn_per_type.by_kind().into_iter().zip(per_type.by_kind())
+            {
+                maximums.push((format!("per_type.{}", kind.as_str()), warn, limit));
+            }
+        }
+        for (name, warn, limit) in maximums {
+            if let (Some(warn), Some(limit)) = (warn, limit)
+                && warn > limit
+            {
+                return Err(ConfigValidationError {
+                    field: format!("limits.warn.{}", name),
+                    message: format!("warning threshold {} exceeds the limit {}", warn, limit),
+                }
+                .into());
+            }
+        }
+        if let (Some(warn), Some(minimum)) = (warn.min_test_ratio, self.limits.min_test_ratio)
+            && warn < minimum
+        {
+            return Err(ConfigValidationError {
+                field: "limits.warn.min_test_ratio".to_string(),
+                message: format!(
+                    "warning threshold {} is below the minimum {}",
+                    warn, minimum
+                ),
+            }
+            .into());
+        }
+
+        let path_lists = [
+            ("classification.test_paths", &self.classification.test_paths),
+            (
+                "classification.ignore_paths",
+                &self.classification.ignore_paths,
+            ),
+            (
+                "classification.generated_paths",
+                &self.classification.generated_paths,
+            ),
+            (
+                "classification.vendored_paths",
+                &self.classification.vendored_paths,
+            ),
+        ];
+        for (field, patterns) in path_lists {
+            if let Some(pattern) = patterns
+                .iter()
+                .find(|p| p.trim_start_matches('!').trim_matches('/').is_empty())
+            {
+                return Err(ConfigValidationError {
+                    field: field.to_string(),
+                    message: format!("pattern matches nothing: '{}'", pattern),
+                }
+                .into());
+            }
+        }
+
+        if let Some(rules) = &self.classification.rules {
+            for (index, rule) in rules.iter().enumerate() {
+                if rule
+                    .path
+                    .as_deref()
+                    .is_some_and(|p| p.trim_matches('/').is_empty())
+                {
+                    return Err(ConfigValidationError {
+                        field: "classification.rules".to_string(),
+                        message: format!("{}: path pattern cannot be empty", rule.label(index)),
+                    }
+                    .into());
+                }
+            }
+        }
+
+        for convention in &self.classification.naming_conventions {
+            if convention.pattern.trim_matches('/').is_empty() {
+                return Err(ConfigValidationError {
+                    field: "classification.naming_conventions".to_string(),
+                    message: "pattern cannot be empty".to_string(),
+                }
+                .into());
+            }
+            if !convention.code_type.is_test_related() {
+                return Err(ConfigValidationError {
+                    field: "classification.naming_conventions".to_string(),
+                    message: format!(
+                        "'{}' must map to a test code type, got {}",
+                        convention.pattern,
+                        convention.code_type.as_str()
+                    ),
+                }
+                .into());
+            }
+        }
+
+        for entry in &self.limits.allowlist {
+            if entry.pattern.trim_matches(':').trim().is_empty() {
+                return Err(ConfigValidationError {
+                    field: "limits.allowlist".to_string(),
+                    message: "pattern cannot be empty".to_string(),
+                }
+                .into());
+            }
+            if entry.reason.trim().is_empty() {
+                return Err(ConfigValidationError {
+                    field: "limits.allowlist".to_string(),
+                    message: format!("'{}' needs a reason", entry.pattern),
+                }
+                .into());
+            }
+        }
+
         let mut seen = std::collections::HashSet::new();
         for author in &self.classification.ignored_authors {
             if author.is_empty() {
@@ -364,14 +829,49 @@ impl Config {
         self.classification
             .test_features
             .iter()
+            .chain(&self.workspace.test_features)
             .map(|s| s.as_str())
             .collect()
     }
 
+    /// Checks if a feature indicates test code
+    ///
+    /// Both configured test features and those derived from Cargo manifests
+    /// are considered.
+    ///
+    /// # Arguments
+    ///
+    /// * `feature` - Feature name to check
+    ///
+    /// # Returns
+    ///
+    /// `true` if the feature is a test feature
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::Config;
+    ///
+    /// let mut config = Config::default();
+    /// assert!(config.is_test_feature("mock"));
+    ///
+    /// config.workspace.test_features.push("fixtures".to_string());
+    /// assert!(config.is_test_feature("fixtures"));
+    /// assert!(!config.is_test_feature("serde"));
+    /// ```
+    pub fn is_test_feature(&self, feature: &str) -> bool {
+        self.classification
+            .test_features
+            .iter()
+            .chain(&self.workspace.test_features)
+            .any(|f| f == feature)
+    }
+
     /// Checks if a path should be ignored
     ///
-    /// Patterns match whole path components: `src/gen` ignores files under
-    /// `src/gen/` but not `src/generic.rs`.
+    /// Plain patterns match whole path components: `src/gen` ignores files
+    /// under `src/gen/` but not `src/generic.rs`. Glob patterns such as
+    /// `crates/*/fixtures/**` and `!` negations follow `.gitignore` rules.
     ///
     /// # Arguments
     ///
@@ -423,11 +923,7 @@ impl Config {
     /// );
     /// ```
     pub fn matched_ignore_pattern(&self, path: &Path) -> Option<&str> {
-        self.classification
-            .ignore_paths
-            .iter()
-            .find(|p| crate::classifier::path_classifier::path_matches_pattern(path, p))
-            .map(|s| s.as_str())
+        path_classifier::matching_pattern(path, &self.classification.ignore_paths)
     }
 
     /// Checks if an author should be ignored
@@ -512,13 +1008,70 @@ impl Config {
     /// assert!(!config.is_test_path(Path::new("src/lib.rs")));
     /// ```
     pub fn is_test_path(&self, path: &Path) -> bool {
+        path_classifier::matching_pattern(path, &self.classification.test_paths).is_some()
+    }
+
+    /// Returns the classification rules in effect
+    ///
+    /// The default rule set is built on every call from the current naming
+    /// conventions; callers classifying many units should build it once.
+    ///
+    /// # Returns
+    ///
+    /// Configured rules, or the default rule set when none are configured
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::Config;
+    ///
+    /// let config = Config::default();
+    /// assert!(!config.classification_rules().is_empty());
+    /// ```
+    pub fn classification_rules(&self) -> Cow<'_, [ClassificationRule]> {
+        match &self.classification.rules {
+            Some(rules) => Cow::Borrowed(rules),
+            None => Cow::Owned(default_rules(self)),
+        }
+    }
+
+    /// Returns the code type assigned to a path by naming conventions
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to check
+    ///
+    /// # Returns
+    ///
+    /// Code type of the first matching convention, or `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::{Config, types::CodeType};
+    ///
+    /// let config = Config::default();
+    /// assert_eq!(
+    ///     config.naming_convention_type(Path::new("src/parser_test.rs")),
+    ///     Some(CodeType::Test)
+    /// );
+    /// assert_eq!(
+    ///     config.naming_convention_type(Path::new("src/mocks/db.rs")),
+    ///     Some(CodeType::TestUtility)
+    /// );
+    /// assert_eq!(config.naming_convention_type(Path::new("src/lib.rs")), None);
+    /// ```
+    pub fn naming_convention_type(&self, path: &Path) -> Option<CodeType> {
         self.classification
-            .test_paths
+            .naming_conventions
             .iter()
-            .any(|p| crate::classifier::path_classifier::path_matches_pattern(path, p))
+            .find(|c| path_classifier::path_matches(path, &c.pattern))
+            .map(|c| c.code_type)
     }
 
-    /// Checks if path is a build script
+    /// Checks if a path matches a configured generated-code path
     ///
     /// # Arguments
     ///
@@ -526,7 +1079,7 @@ impl Config {
     ///
     /// # Returns
     ///
-    /// `true` if path is build.rs
+    /// `true` if path is in a generated-code location
     ///
     /// # Examples
     ///
@@ -535,12 +1088,216 @@ impl Config {
     ///
     /// use rust_diff_analyzer::Config;
     ///
-    /// let config = Config::default();
+    /// let mut config = Config::default();
+    /// config
+    ///     .classification
+    ///     .generated_paths
+    ///     .push("src/proto/".to_string());
+    /// assert!(config.is_generated_path(Path::new("src/proto/api.rs")));
+    /// assert!(!config.is_generated_path(Path::new("src/lib.rs")));
+    /// ```
+    pub fn is_generated_path(&self, path: &Path) -> bool {
+        path_classifier::matching_pattern(path, &self.classification.generated_paths).is_some()
+    }
+
+    /// Checks if path contains vendored third-party code
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to check
+    ///
+    /// # Returns
+    ///
+    /// `true` if path matches `vendored_paths`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::Config;
+    ///
+    /// let mut config = Config::default();
+    /// config
+    ///     .classification
+    ///     .vendored_paths
+    ///     .push("third_party/".to_string());
+    /// assert!(config.is_vendored_path(Path::new("third_party/zstd/src/lib.rs")));
+    /// assert!(!config.is_vendored_path(Path::new("src/vendors.rs")));
+    /// ```
+    pub fn is_vendored_path(&self, path: &Path) -> bool {
+        path_classifier::matching_pattern(path, &self.classification.vendored_paths).is_some()
+    }
+
+    /// Checks if changes of a code type count toward limits
+    ///
+    /// Production code always counts; generated code counts only when
+    /// `limits.count_generated` is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `code_type` - Classification of the change
+    ///
+    /// # Returns
+    ///
+    /// `true` if the change counts toward limits
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{Config, types::CodeType};
+    ///
+    /// let mut config = Config::default();
+    /// assert!(config.counts_toward_limits(CodeType::Production));
+    /// assert!(!config.counts_toward_limits(CodeType::Generated));
+    ///
+    /// config.limits.count_generated = true;
+    /// assert!(config.counts_toward_limits(CodeType::Generated));
+    /// ```
+    pub fn counts_toward_limits(&self, code_type: CodeType) -> bool {
+        code_type.is_production() || (code_type.is_generated() && self.limits.count_generated)
+    }
+
+    /// Returns the allowlist entry covering a unit, if any
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - Semantic unit
+    /// * `path` - Path of the file containing the unit
+    ///
+    /// # Returns
+    ///
+    /// First matching entry, or `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::{
+    ///     config::ConfigBuilder,
+    ///     types::{LineSpan, SemanticUnit, SemanticUnitKind, Visibility},
+    /// };
+    ///
+    /// let config = ConfigBuilder::new()
+    ///     .add_allowlist_entry("crate::proto::*", "generated protocol tables")
+    ///     .build();
+    /// let unit = SemanticUnit::new(
+    ///     SemanticUnitKind::Function,
+    ///     "lookup".to_string(),
+    ///     Visibility::Public,
+    ///     LineSpan::new(1, 300),
+    ///     vec![],
+    /// );
+    ///
+    /// let entry = config.allowlist_entry(&unit, Path::new("src/proto/opcodes.rs"));
+    /// assert_eq!(
+    ///     entry.map(|e| e.reason.as_str()),
+    ///     Some("generated protocol tables")
+    /// );
+    /// assert!(
+    ///     config
+    ///         .allowlist_entry(&unit, Path::new("src/vm.rs"))
+    ///         .is_none()
+    /// );
+    /// ```
+    pub fn allowlist_entry(&self, unit: &SemanticUnit, path: &Path) -> Option<&AllowlistEntry> {
+        if self.limits.allowlist.is_empty() {
+            return None;
+        }
+
+        let unit_path = allowlist::unit_path(unit, path);
+        self.limits
+            .allowlist
+            .iter()
+            .find(|entry| allowlist::pattern_matches(&entry.pattern, &unit_path))
+    }
+
+    /// Checks if path is a build script
+    ///
+    /// Besides files named `build.rs`, files reachable only from a build
+    /// script and files of build-only packages discovered in the workspace
+    /// count as build scripts.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to check
+    ///
+    /// # Returns
+    ///
+    /// `true` if path is build-script code
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// use rust_diff_analyzer::Config;
+    ///
+    /// let mut config = Config::default();
     /// assert!(config.is_build_script(Path::new("build.rs")));
     /// assert!(!config.is_build_script(Path::new("src/lib.rs")));
+    ///
+    /// config
+    ///     .workspace
+    ///     .build_dependency_dirs
+    ///     .push(PathBuf::from("crates/codegen"));
+    /// assert!(config.is_build_script(Path::new("crates/codegen/src/lib.rs")));
     /// ```
     pub fn is_build_script(&self, path: &Path) -> bool {
-        path.file_name().map(|n| n == "build.rs").unwrap_or(false)
+        self.build_script_source(path).is_some()
+    }
+
+    /// Explains why a path is build-script code
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to check
+    ///
+    /// # Returns
+    ///
+    /// Reason the path is build-script code, or `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// use rust_diff_analyzer::Config;
+    ///
+    /// let mut config = Config::default();
+    /// config
+    ///     .workspace
+    ///     .build_script_files
+    ///     .push(PathBuf::from("tools/gen.rs"));
+    /// assert_eq!(
+    ///     config
+    ///         .build_script_source(Path::new("tools/gen.rs"))
+    ///         .as_deref(),
+    ///     Some("path is reachable from a build script")
+    /// );
+    /// assert!(
+    ///     config
+    ///         .build_script_source(Path::new("src/lib.rs"))
+    ///         .is_none()
+    /// );
+    /// ```
+    pub fn build_script_source(&self, path: &Path) -> Option<String> {
+        let workspace = &self.workspace;
+        if workspace.build_script_files.iter().any(|f| f == path) {
+            return Some("path is reachable from a build script".to_string());
+        }
+        if let Some(dir) = workspace
+            .build_dependency_dirs
+            .iter()
+            .find(|dir| path.starts_with(dir))
+        {
+            return Some(format!("path is in build-only package `{}`", dir.display()));
+        }
+        if path.file_name().is_some_and(|n| n == "build.rs") {
+            return Some("path is a build script".to_string());
+        }
+        None
     }
 }
 
@@ -572,7 +1329,7 @@ impl ConfigBuilder {
     ///
     /// # Arguments
     ///
-    /// * `format` - Output format to use
+    /// * `format` - Built-in format or name of a registered formatter
     ///
     /// # Returns
     ///
@@ -586,9 +1343,10 @@ impl ConfigBuilder {
     /// let config = ConfigBuilder::new()
     ///     .output_format(OutputFormat::Json)
     ///     .build();
+    /// assert_eq!(config.output.format, "json");
     /// ```
-    pub fn output_format(mut self, format: OutputFormat) -> Self {
-        self.config.output.format = format;
+    pub fn output_format(mut self, format: impl Into<String>) -> Self {
+        self.config.output.format = format.into();
         self
     }
 
@@ -734,6 +1492,63 @@ impl ConfigBuilder {
         self
     }
 
+    /// Adds a naming convention
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - Path pattern
+    /// * `code_type` - Code type for matching files
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{config::ConfigBuilder, types::CodeType};
+    ///
+    /// let config = ConfigBuilder::new()
+    ///     .add_naming_convention("**/*_spec.rs", CodeType::Test)
+    ///     .build();
+    /// ```
+    pub fn add_naming_convention(mut self, pattern: &str, code_type: CodeType) -> Self {
+        self.config
+            .classification
+            .naming_conventions
+            .push(NamingConvention::new(pattern, code_type));
+        self
+    }
+
+    /// Adds a unit allowlist entry
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - Qualified unit name or glob
+    /// * `reason` - Why matching units are exempt
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::config::ConfigBuilder;
+    ///
+    /// let config = ConfigBuilder::new()
+    ///     .add_allowlist_entry("Config::default", "mechanical field list")
+    ///     .build();
+    /// assert_eq!(config.limits.allowlist.len(), 1);
+    /// ```
+    pub fn add_allowlist_entry(mut self, pattern: &str, reason: &str) -> Self {
+        self.config
+            .limits
+            .allowlist
+            .push(AllowlistEntry::new(pattern, reason));
+        self
+    }
+
     /// Adds a path to ignore
     ///
     /// # Arguments
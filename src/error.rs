@@ -24,6 +24,25 @@ impl FileReadError {
     }
 }
 
+/// Error for file write operations
+#[derive(Debug, Error)]
+#[error("failed to write file '{path}': {source}")]
+#[app_error(kind = AppErrorKind::Internal, code = AppCode::Internal, message)]
+pub struct FileWriteError {
+    pub path: String,
+    pub source: std::io::Error,
+}
+
+impl FileWriteError {
+    /// Creates a new FileWriteError from a path and source error
+    pub fn new(path: impl Into<PathBuf>, source: std::io::Error) -> Self {
+        Self {
+            path: path.into().display().to_string(),
+            source,
+        }
+    }
+}
+
 /// Error for Rust source code parsing
 #[derive(Debug, Error)]
 #[error("failed to parse '{path}': {message}")]
@@ -70,6 +89,33 @@ impl ConfigError {
     }
 }
 
+/// Error for reading a source archive
+#[derive(Debug, Error)]
+#[error("failed to read archive: {message}")]
+#[app_error(kind = AppErrorKind::BadRequest, code = AppCode::BadRequest, message)]
+pub struct ArchiveError {
+    pub message: String,
+}
+
+/// Error for Cargo manifest parsing
+#[derive(Debug, Error)]
+#[error("failed to parse manifest '{path}': {message}")]
+#[app_error(kind = AppErrorKind::BadRequest, code = AppCode::BadRequest, message)]
+pub struct ManifestError {
+    pub path: String,
+    pub message: String,
+}
+
+impl ManifestError {
+    /// Creates a new ManifestError from a path and message
+    pub fn new(path: impl Into<PathBuf>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into().display().to_string(),
+            message: message.into(),
+        }
+    }
+}
+
 /// Error for invalid configuration values
 #[derive(Debug, Error)]
 #[error("invalid config field '{field}': {message}")]
@@ -1,8 +1,12 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+pub mod attributes;
 pub mod diff_parser;
 pub mod hunk;
+pub mod pattern;
 
+pub use attributes::{AttributeState, GitAttributes};
 pub use diff_parser::{FileDiff, parse_diff};
 pub use hunk::{Hunk, HunkLine, LineType};
+pub use pattern::GlobPattern;
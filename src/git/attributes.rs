@@ -0,0 +1,370 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use masterror::AppError;
+
+use super::pattern::GlobPattern;
+use crate::error::FileReadError;
+
+/// Attributes that exclude a file from analysis when set
+pub const EXCLUDING_ATTRIBUTES: [&str; 2] = ["linguist-generated", "linguist-vendored"];
+
+/// Custom attribute controlling the analyzer, e.g. `rust-diff-analyzer=ignore`
+pub const ANALYZER_ATTRIBUTE: &str = "rust-diff-analyzer";
+
+/// State of an attribute assigned by a `.gitattributes` line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttributeState {
+    /// `attr` or `attr=true`
+    Set,
+    /// `-attr` or `attr=false`
+    Unset,
+    /// `!attr`: back to unspecified, overriding earlier lines
+    Unspecified,
+    /// `attr=value`
+    Value(String),
+}
+
+impl AttributeState {
+    fn parse(token: &str) -> (String, Self) {
+        if let Some(name) = token.strip_prefix('-') {
+            return (name.to_string(), Self::Unset);
+        }
+        if let Some(name) = token.strip_prefix('!') {
+            return (name.to_string(), Self::Unspecified);
+        }
+        match token.split_once('=') {
+            Some((name, "true")) => (name.to_string(), Self::Set),
+            Some((name, "false")) => (name.to_string(), Self::Unset),
+            Some((name, value)) => (name.to_string(), Self::Value(value.to_string())),
+            None => (token.to_string(), Self::Set),
+        }
+    }
+}
+
+/// One `pattern attr...` line of a `.gitattributes` file
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct AttributeRule {
+    pattern: GlobPattern,
+    attributes: Vec<(String, AttributeState)>,
+}
+
+/// Parsed `.gitattributes` rules
+///
+/// Later lines override earlier ones for the same attribute, following git.
+/// Patterns use gitattributes semantics: they match the path itself, so a
+/// directory is covered with `dir/**` (or the commonly used `dir/` form).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GitAttributes {
+    rules: Vec<AttributeRule>,
+}
+
+impl GitAttributes {
+    /// Parses `.gitattributes` content
+    ///
+    /// Comments, blank lines, `[attr]` macro definitions and negated
+    /// patterns (which git rejects) are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - File content
+    ///
+    /// # Returns
+    ///
+    /// Parsed attributes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::git::GitAttributes;
+    ///
+    /// let attributes = GitAttributes::parse("src/proto/** linguist-generated\n");
+    /// assert!(attributes.is_set(Path::new("src/proto/api.rs"), "linguist-generated"));
+    /// ```
+    pub fn parse(content: &str) -> Self {
+        let mut rules = Vec::new();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with("[attr]") {
+                continue;
+            }
+
+            let mut tokens = line.split_whitespace();
+            let pattern = match tokens.next() {
+                Some(pattern) if !pattern.starts_with('!') => pattern,
+                _ => continue,
+            };
+            let attributes: Vec<_> = tokens.map(AttributeState::parse).collect();
+            if attributes.is_empty() {
+                continue;
+            }
+
+            rules.push(AttributeRule {
+                pattern: GlobPattern::new(pattern),
+                attributes,
+            });
+        }
+
+        Self { rules }
+    }
+
+    /// Loads `.gitattributes` from a repository root
+    ///
+    /// # Arguments
+    ///
+    /// * `base_dir` - Repository root directory
+    ///
+    /// # Returns
+    ///
+    /// Parsed attributes, empty when the file does not exist
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file exists but cannot be read
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::git::GitAttributes;
+    ///
+    /// let attributes = GitAttributes::from_dir(Path::new("does-not-exist")).unwrap();
+    /// assert!(attributes.is_empty());
+    /// ```
+    pub fn from_dir(base_dir: &Path) -> Result<Self, AppError> {
+        let path: PathBuf = base_dir.join(".gitattributes");
+        match fs::read_to_string(&path) {
+            Ok(content) => Ok(Self::parse(&content)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(FileReadError::new(path, e).into()),
+        }
+    }
+
+    /// Checks whether no rules were loaded
+    ///
+    /// # Returns
+    ///
+    /// `true` if there are no rules
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::git::GitAttributes;
+    ///
+    /// assert!(GitAttributes::parse("# only a comment").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns the effective state of an attribute for a path
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Repository-relative path
+    /// * `name` - Attribute name
+    ///
+    /// # Returns
+    ///
+    /// State from the last matching line, or `None` if unspecified
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::git::{AttributeState, GitAttributes};
+    ///
+    /// let attributes =
+    ///     GitAttributes::parse("*.rs rust-diff-analyzer=ignore\nsrc/*.rs -rust-diff-analyzer\n");
+    /// assert_eq!(
+    ///     attributes.get(Path::new("tests/a.rs"), "rust-diff-analyzer"),
+    ///     Some(&AttributeState::Value("ignore".to_string()))
+    /// );
+    /// assert_eq!(
+    ///     attributes.get(Path::new("src/a.rs"), "rust-diff-analyzer"),
+    ///     Some(&AttributeState::Unset)
+    /// );
+    /// ```
+    pub fn get(&self, path: &Path, name: &str) -> Option<&AttributeState> {
+        self.rules
+            .iter()
+            .rev()
+            .filter(|rule| rule.pattern.matches(path))
+            .find_map(|rule| {
+                rule.attributes
+                    .iter()
+                    .rev()
+                    .find(|(attr, _)| attr == name)
+                    .map(|(_, state)| state)
+            })
+            .filter(|state| **state != AttributeState::Unspecified)
+    }
+
+    /// Checks whether an attribute is set for a path
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Repository-relative path
+    /// * `name` - Attribute name
+    ///
+    /// # Returns
+    ///
+    /// `true` if the attribute is set (`attr` or `attr=true`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::git::GitAttributes;
+    ///
+    /// let attributes =
+    ///     GitAttributes::parse("vendor/** linguist-vendored\nvendor/ours/** -linguist-vendored\n");
+    /// assert!(attributes.is_set(Path::new("vendor/dep/lib.rs"), "linguist-vendored"));
+    /// assert!(!attributes.is_set(Path::new("vendor/ours/lib.rs"), "linguist-vendored"));
+    /// ```
+    pub fn is_set(&self, path: &Path, name: &str) -> bool {
+        matches!(self.get(path, name), Some(AttributeState::Set))
+    }
+
+    /// Returns the attribute excluding a path from analysis, if any
+    ///
+    /// A path is excluded by `linguist-generated`, `linguist-vendored` or
+    /// `rust-diff-analyzer=ignore`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Repository-relative path
+    ///
+    /// # Returns
+    ///
+    /// The excluding attribute as written in `.gitattributes` form, or `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::git::GitAttributes;
+    ///
+    /// let attributes = GitAttributes::parse("fixtures/** rust-diff-analyzer=ignore\n");
+    /// assert_eq!(
+    ///     attributes
+    ///         .exclusion(Path::new("fixtures/sample.rs"))
+    ///         .as_deref(),
+    ///     Some("rust-diff-analyzer=ignore")
+    /// );
+    /// assert_eq!(attributes.exclusion(Path::new("src/lib.rs")), None);
+    /// ```
+    pub fn exclusion(&self, path: &Path) -> Option<String> {
+        if let Some(name) = EXCLUDING_ATTRIBUTES
+            .iter()
+            .find(|name| self.is_set(path, name))
+        {
+            return Some((*name).to_string());
+        }
+
+        match self.get(path, ANALYZER_ATTRIBUTE) {
+            Some(AttributeState::Value(value)) if value == "ignore" => {
+                Some(format!("{}={}", ANALYZER_ATTRIBUTE, value))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_skips_comments_macros_and_negations() {
+        let attributes = GitAttributes::parse(
+            "# comment\n\n[attr]binary -diff -merge -text\n!*.rs linguist-generated\n*.rs\n",
+        );
+        assert!(attributes.is_empty());
+    }
+
+    #[test]
+    fn test_linguist_attributes_exclude() {
+        let attributes = GitAttributes::parse(
+            "*.pb.rs linguist-generated=true\nthird_party/** linguist-vendored\n",
+        );
+
+        assert_eq!(
+            attributes.exclusion(Path::new("src/api.pb.rs")).as_deref(),
+            Some("linguist-generated")
+        );
+        assert_eq!(
+            attributes
+                .exclusion(Path::new("third_party/dep/src/lib.rs"))
+                .as_deref(),
+            Some("linguist-vendored")
+        );
+        assert_eq!(attributes.exclusion(Path::new("src/lib.rs")), None);
+    }
+
+    #[test]
+    fn test_later_lines_override_earlier_ones() {
+        let attributes = GitAttributes::parse(
+            "gen/** linguist-generated\ngen/keep.rs linguist-generated=false\ngen/reset.rs \
+             !linguist-generated\n",
+        );
+
+        assert!(attributes.is_set(Path::new("gen/a.rs"), "linguist-generated"));
+        assert_eq!(
+            attributes.get(Path::new("gen/keep.rs"), "linguist-generated"),
+            Some(&AttributeState::Unset)
+        );
+        assert_eq!(
+            attributes.get(Path::new("gen/reset.rs"), "linguist-generated"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_directory_pattern_applies_to_nested_contents() {
+        let attributes = GitAttributes::parse(
+            "generated/ linguist-generated\n/third_party/ linguist-vendored\n",
+        );
+
+        assert!(attributes.is_set(
+            Path::new("crates/api/src/generated/v1/user.rs"),
+            "linguist-generated"
+        ));
+        assert!(attributes.is_set(Path::new("generated/user.rs"), "linguist-generated"));
+        assert!(!attributes.is_set(Path::new("src/generated.rs"), "linguist-generated"));
+        assert!(attributes.is_set(Path::new("third_party/dep/src/lib.rs"), "linguist-vendored"));
+        assert!(!attributes.is_set(Path::new("crates/third_party/lib.rs"), "linguist-vendored"));
+    }
+
+    #[test]
+    fn test_analyzer_attribute_other_values_do_not_exclude() {
+        let attributes = GitAttributes::parse("*.rs rust-diff-analyzer=analyze\n");
+        assert_eq!(attributes.exclusion(Path::new("src/lib.rs")), None);
+    }
+
+    #[test]
+    fn test_from_dir_reads_root_file() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        fs::write(
+            dir.path().join(".gitattributes"),
+            "vendor/ linguist-vendored\n",
+        )
+        .expect("write");
+
+        let attributes = GitAttributes::from_dir(dir.path()).expect("load");
+        assert!(attributes.is_set(Path::new("vendor/dep/lib.rs"), "linguist-vendored"));
+    }
+}
@@ -0,0 +1,403 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::path::Path;
+
+/// Single token of a path component pattern
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    /// Literal character
+    Literal(char),
+    /// `*`: any run of characters within a component
+    AnyRun,
+    /// `?`: any single character
+    AnyChar,
+    /// `[...]`: character class with inclusive ranges
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+/// Segment of a pattern between `/` separators
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// `**`: zero or more whole components
+    AnyDepth,
+    /// Pattern for exactly one component
+    Component(Vec<Token>),
+}
+
+/// Glob pattern with gitignore/gitattributes semantics
+///
+/// Supports `*` and `?` within a component, `[a-z]` / `[!a-z]` character
+/// classes, `\` escapes and `**` spanning any number of directories. A
+/// pattern without a `/` (other than a trailing one) matches at any depth;
+/// otherwise it is anchored to the repository root. A trailing `/` restricts
+/// the pattern to directories, which for file paths means one of their parent
+/// directories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlobPattern {
+    source: String,
+    segments: Vec<Segment>,
+    dir_only: bool,
+    literal: bool,
+}
+
+impl GlobPattern {
+    /// Parses a glob pattern
+    ///
+    /// Malformed character classes are treated as literal text, as git does.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - Pattern in gitignore syntax, without `!` negation
+    ///
+    /// # Returns
+    ///
+    /// Parsed pattern
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::git::GlobPattern;
+    ///
+    /// let pattern = GlobPattern::new("src/**/*.pb.rs");
+    /// assert!(pattern.matches(Path::new("src/api/v1/user.pb.rs")));
+    /// assert!(!pattern.matches(Path::new("crate/src/user.pb.rs")));
+    ///
+    /// let basename = GlobPattern::new("*.pb.rs");
+    /// assert!(basename.matches(Path::new("crate/src/user.pb.rs")));
+    /// ```
+    pub fn new(pattern: &str) -> Self {
+        let dir_only = pattern.ends_with('/');
+        let trimmed = pattern.trim_end_matches('/');
+        let anchored = trimmed.contains('/');
+        let body = trimmed.strip_prefix('/').unwrap_or(trimmed);
+
+        let mut segments = Vec::new();
+        if !anchored {
+            segments.push(Segment::AnyDepth);
+        }
+        for part in body.split('/').filter(|p| !p.is_empty()) {
+            if part == "**" {
+                if segments.last() != Some(&Segment::AnyDepth) {
+                    segments.push(Segment::AnyDepth);
+                }
+            } else {
+                segments.push(Segment::Component(parse_component(part)));
+            }
+        }
+
+        let literal = !body.contains(['*', '?', '[', '\\']);
+
+        Self {
+            source: pattern.to_string(),
+            segments,
+            dir_only,
+            literal,
+        }
+    }
+
+    /// Returns the pattern as written
+    ///
+    /// # Returns
+    ///
+    /// Original pattern text
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::git::GlobPattern;
+    ///
+    /// assert_eq!(GlobPattern::new("vendor/**").as_str(), "vendor/**");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Checks whether the pattern contains no wildcards or classes
+    ///
+    /// # Returns
+    ///
+    /// `true` if every character is matched literally
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::git::GlobPattern;
+    ///
+    /// assert!(GlobPattern::new("tests/").is_literal());
+    /// assert!(!GlobPattern::new("tests/*.rs").is_literal());
+    /// ```
+    pub fn is_literal(&self) -> bool {
+        self.literal
+    }
+
+    /// Checks whether a path matches the pattern
+    ///
+    /// Directory-only patterns match a file when they match one of its
+    /// parent directories; other patterns must match the path itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Repository-relative path
+    ///
+    /// # Returns
+    ///
+    /// `true` if the path matches
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::git::GlobPattern;
+    ///
+    /// let pattern = GlobPattern::new("vendor/");
+    /// assert!(pattern.matches(Path::new("crates/vendor/lib.rs")));
+    /// assert!(!pattern.matches(Path::new("vendor")));
+    /// ```
+    pub fn matches(&self, path: &Path) -> bool {
+        let components = path_components(path);
+        if self.dir_only {
+            (1..components.len()).any(|len| self.matches_components(&components[..len]))
+        } else {
+            self.matches_components(&components)
+        }
+    }
+
+    /// Checks whether a path or any of its parent directories matches
+    ///
+    /// This is the gitignore notion of matching: excluding a directory
+    /// excludes everything below it.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Repository-relative path
+    ///
+    /// # Returns
+    ///
+    /// `true` if the path or one of its ancestors matches
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::git::GlobPattern;
+    ///
+    /// let pattern = GlobPattern::new("generated");
+    /// assert!(pattern.matches_within(Path::new("src/generated/api.rs")));
+    /// assert!(!pattern.matches(Path::new("src/generated/api.rs")));
+    /// ```
+    pub fn matches_within(&self, path: &Path) -> bool {
+        let components = path_components(path);
+        let full = if self.dir_only {
+            components.len().saturating_sub(1)
+        } else {
+            components.len()
+        };
+        (1..=full).any(|len| self.matches_components(&components[..len]))
+    }
+
+    fn matches_components(&self, components: &[String]) -> bool {
+        match_segments(&self.segments, components)
+    }
+}
+
+fn path_components(path: &Path) -> Vec<String> {
+    path.components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .filter(|c| c != ".")
+        .collect()
+}
+
+fn parse_component(part: &str) -> Vec<Token> {
+    let chars: Vec<char> = part.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '*' => {
+                if tokens.last() != Some(&Token::AnyRun) {
+                    tokens.push(Token::AnyRun);
+                }
+                i += 1;
+            }
+            '?' => {
+                tokens.push(Token::AnyChar);
+                i += 1;
+            }
+            '\\' if i + 1 < chars.len() => {
+                tokens.push(Token::Literal(chars[i + 1]));
+                i += 2;
+            }
+            '[' => match parse_class(&chars, i) {
+                Some((token, next)) => {
+                    tokens.push(token);
+                    i = next;
+                }
+                None => {
+                    tokens.push(Token::Literal('['));
+                    i += 1;
+                }
+            },
+            c => {
+                tokens.push(Token::Literal(c));
+                i += 1;
+            }
+        }
+    }
+
+    tokens
+}
+
+/// Parses a character class starting at `chars[start] == '['`, returning the
+/// token and the index after the closing `]`, or `None` if unterminated.
+fn parse_class(chars: &[char], start: usize) -> Option<(Token, usize)> {
+    let mut i = start + 1;
+    let negated = matches!(chars.get(i), Some('!') | Some('^'));
+    if negated {
+        i += 1;
+    }
+
+    let mut ranges = Vec::new();
+    let mut first = true;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == ']' && !first {
+            return Some((Token::Class { negated, ranges }, i + 1));
+        }
+        first = false;
+
+        let low = if c == '\\' && i + 1 < chars.len() {
+            i += 1;
+            chars[i]
+        } else {
+            c
+        };
+        if chars.get(i + 1) == Some(&'-') && chars.get(i + 2).is_some_and(|&h| h != ']') {
+            ranges.push((low, chars[i + 2]));
+            i += 3;
+        } else {
+            ranges.push((low, low));
+            i += 1;
+        }
+    }
+
+    None
+}
+
+fn match_segments(segments: &[Segment], components: &[String]) -> bool {
+    match segments.split_first() {
+        None => components.is_empty(),
+        Some((Segment::AnyDepth, rest)) => {
+            (0..=components.len()).any(|skip| match_segments(rest, &components[skip..]))
+        }
+        Some((Segment::Component(tokens), rest)) => match components.split_first() {
+            Some((component, remaining)) => {
+                let chars: Vec<char> = component.chars().collect();
+                match_tokens(tokens, &chars) && match_segments(rest, remaining)
+            }
+            None => false,
+        },
+    }
+}
+
+fn match_tokens(tokens: &[Token], chars: &[char]) -> bool {
+    match tokens.split_first() {
+        None => chars.is_empty(),
+        Some((Token::AnyRun, rest)) => {
+            (0..=chars.len()).any(|skip| match_tokens(rest, &chars[skip..]))
+        }
+        Some((token, rest)) => match chars.split_first() {
+            Some((c, remaining)) => token_matches(token, *c) && match_tokens(rest, remaining),
+            None => false,
+        },
+    }
+}
+
+fn token_matches(token: &Token, c: char) -> bool {
+    match token {
+        Token::Literal(l) => *l == c,
+        Token::AnyChar => true,
+        Token::AnyRun => true,
+        Token::Class { negated, ranges } => {
+            let inside = ranges.iter().any(|(low, high)| (*low..=*high).contains(&c));
+            inside != *negated
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, path: &str) -> bool {
+        GlobPattern::new(pattern).matches(Path::new(path))
+    }
+
+    #[test]
+    fn test_unanchored_pattern_matches_basename_at_any_depth() {
+        assert!(matches("*.rs", "lib.rs"));
+        assert!(matches("*.rs", "src/deep/lib.rs"));
+        assert!(!matches("*.rs", "src/lib.rsx"));
+        assert!(matches("build.rs", "crates/core/build.rs"));
+    }
+
+    #[test]
+    fn test_anchored_pattern_matches_from_root() {
+        assert!(matches("/build.rs", "build.rs"));
+        assert!(!matches("/build.rs", "crates/core/build.rs"));
+        assert!(matches("src/*.rs", "src/lib.rs"));
+        assert!(!matches("src/*.rs", "src/api/lib.rs"));
+        assert!(!matches("src/*.rs", "crate/src/lib.rs"));
+    }
+
+    #[test]
+    fn test_double_star() {
+        assert!(matches("vendor/**", "vendor/a/b/c.rs"));
+        assert!(matches("**/proto/*.rs", "proto/api.rs"));
+        assert!(matches("**/proto/*.rs", "crates/x/proto/api.rs"));
+        assert!(matches("src/**/gen.rs", "src/gen.rs"));
+        assert!(matches("src/**/gen.rs", "src/a/b/gen.rs"));
+        assert!(!matches("src/**/gen.rs", "lib/a/gen.rs"));
+    }
+
+    #[test]
+    fn test_question_mark_and_classes() {
+        assert!(matches("v?.rs", "v1.rs"));
+        assert!(!matches("v?.rs", "v10.rs"));
+        assert!(matches("v[0-9].rs", "v7.rs"));
+        assert!(!matches("v[0-9].rs", "vx.rs"));
+        assert!(matches("v[!0-9].rs", "vx.rs"));
+        assert!(!matches("v[!0-9].rs", "v7.rs"));
+        assert!(matches("a[].rs", "a[].rs"));
+    }
+
+    #[test]
+    fn test_escapes() {
+        assert!(matches("\\*.rs", "*.rs"));
+        assert!(!matches("\\*.rs", "a.rs"));
+    }
+
+    #[test]
+    fn test_directory_only_pattern() {
+        assert!(matches("generated/", "src/generated/api.rs"));
+        assert!(!matches("generated/", "src/generated"));
+        assert!(matches("/third_party/", "third_party/x/y.rs"));
+        assert!(!matches("/third_party/", "src/third_party/y.rs"));
+    }
+
+    #[test]
+    fn test_matches_within_checks_ancestors() {
+        let pattern = GlobPattern::new("src/gen");
+        assert!(pattern.matches_within(Path::new("src/gen/a.rs")));
+        assert!(!pattern.matches_within(Path::new("src/generic.rs")));
+        assert!(!GlobPattern::new("gen/").matches_within(Path::new("gen")));
+    }
+}
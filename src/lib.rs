@@ -2,17 +2,21 @@
 // SPDX-License-Identifier: MIT
 
 pub mod analysis;
+pub mod analyzer;
 pub mod classifier;
 pub mod config;
 pub mod error;
 pub mod git;
 pub mod output;
+pub mod source;
 pub mod types;
+pub mod workspace;
 
+pub use analyzer::Analyzer;
 pub use config::Config;
 pub use error::{
-    ConfigError, ConfigValidationError, DiffParseError, FileReadError, IoError,
-    LimitExceededError, OutputError, ParseError,
+    ArchiveError, ConfigError, ConfigValidationError, DiffParseError, FileReadError,
+    FileWriteError, IoError, LimitExceededError, ManifestError, OutputError, ParseError,
 };
 pub use masterror::AppError;
 pub use types::{AnalysisResult, Change, CodeType, SemanticUnit, Summary};
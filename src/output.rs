@@ -4,9 +4,22 @@
 pub mod comment;
 pub mod formatter;
 pub mod github;
+pub mod gitlab;
+pub mod human;
 pub mod json;
+pub mod junit;
+pub mod registry;
+pub mod sarif;
 
-pub use comment::{format_comment, get_comment_marker};
+pub use comment::{
+    CommentFlavor, CommentFormatter, GitlabCommentFormatter, format_comment, format_comment_for,
+    get_comment_marker,
+};
 pub use formatter::{Formatter, format_output};
-pub use github::GithubFormatter;
+pub use github::{GithubAnnotationsFormatter, GithubFormatter, format_step_outputs};
+pub use gitlab::GitlabFormatter;
+pub use human::HumanFormatter;
 pub use json::JsonFormatter;
+pub use junit::JunitFormatter;
+pub use registry::FormatterRegistry;
+pub use sarif::SarifFormatter;
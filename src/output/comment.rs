@@ -3,10 +3,15 @@
 
 use std::fmt::Write;
 
+use masterror::AppError;
+
+use super::formatter::Formatter;
 use crate::{
-    classifier::rules::exceeded_per_type_limits,
     config::Config,
-    types::{AnalysisResult, Change, ExclusionReason},
+    types::{
+        AnalysisResult, Change, CodeType, ExclusionReason, LimitKind, LimitStatus, LimitViolation,
+        Suppression,
+    },
 };
 
 /// Escapes text for a markdown table cell rendered as inline code
@@ -17,13 +22,106 @@ fn escape_cell(text: &str) -> String {
     text.replace('|', "\\|").replace('`', "'")
 }
 
-/// Returns the status icon for a limit comparison
-fn status_icon(exceeded: bool) -> &'static str {
-    if exceeded { "❌" } else { "✅" }
+/// Renders the suppression column, escaping the allowlist pattern and reason
+fn suppression_cell(suppression: &Suppression) -> String {
+    match suppression {
+        Suppression::Allowlist { pattern, reason } => {
+            format!(
+                "allowlist `{}`: {}",
+                escape_cell(pattern),
+                escape_cell(reason)
+            )
+        }
+        other => other.describe(),
+    }
+}
+
+/// Renders a limit table cell with the optional warning threshold
+fn limit_cell(limit: Option<usize>, warn: Option<usize>) -> String {
+    let limit = limit.map_or_else(|| "-".to_string(), |limit| limit.to_string());
+    match warn {
+        Some(warn) => format!("{} (warn > {})", limit, warn),
+        None => limit,
+    }
+}
+
+/// Appends items as a list inside the current blockquote
+fn push_quoted_list(output: &mut String, items: &[String]) {
+    if items.is_empty() {
+        return;
+    }
+    output.push_str(">\n");
+    for item in items {
+        let _ = writeln!(output, "> - {}", item);
+    }
+}
+
+/// Describes the violations of one severity
+fn describe_violations(result: &AnalysisResult, severity: LimitStatus) -> Vec<String> {
+    result
+        .violations
+        .iter()
+        .filter(|v| v.severity == severity)
+        .map(LimitViolation::describe)
+        .collect()
+}
+
+/// Returns the status icon of a limit over all counted changes
+fn row_icon(result: &AnalysisResult, limit: LimitKind) -> &'static str {
+    result
+        .violation(limit)
+        .map_or(LimitStatus::Ok, |v| v.severity)
+        .icon()
 }
 
 const COMMENT_MARKER: &str = "<!-- rust-diff-analyzer-comment -->";
 
+/// Markdown dialect a comment is rendered for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CommentFlavor {
+    /// GitHub-flavored markdown with `> [!CAUTION]` style alerts
+    #[default]
+    Github,
+    /// GitLab-flavored markdown, where alerts become plain blockquotes
+    /// led by the status icon
+    Gitlab,
+}
+
+/// Formatter for markdown PR comments, see [`format_comment`]
+pub struct CommentFormatter;
+
+impl Formatter for CommentFormatter {
+    fn format(&self, result: &AnalysisResult, config: &Config) -> Result<String, AppError> {
+        Ok(format_comment(result, config))
+    }
+}
+
+/// Formatter for GitLab merge request notes
+pub struct GitlabCommentFormatter;
+
+impl Formatter for GitlabCommentFormatter {
+    fn format(&self, result: &AnalysisResult, config: &Config) -> Result<String, AppError> {
+        Ok(format_comment_for(result, config, CommentFlavor::Gitlab))
+    }
+}
+
+/// Starts the status blockquote, leaving the line open for the headline
+fn push_alert(output: &mut String, flavor: CommentFlavor, status: LimitStatus) {
+    match flavor {
+        CommentFlavor::Github => {
+            let alert = match status {
+                LimitStatus::Fail => "CAUTION",
+                LimitStatus::Warn => "WARNING",
+                LimitStatus::Ok => "TIP",
+            };
+            let _ = write!(output, "> [!{}]\n> ", alert);
+        }
+        CommentFlavor::Gitlab => {
+            let _ = write!(output, "> {} ", status.icon());
+        }
+    }
+}
+
 const MAX_SKIPPED_LISTED: usize = 10;
 
 /// Formats analysis result as a markdown PR comment
@@ -52,6 +150,40 @@ const MAX_SKIPPED_LISTED: usize = 10;
 /// assert!(output.contains("Rust Diff Analysis"));
 /// ```
 pub fn format_comment(result: &AnalysisResult, config: &Config) -> String {
+    format_comment_for(result, config, CommentFlavor::Github)
+}
+
+/// Formats analysis result as a markdown comment for a code host
+///
+/// # Arguments
+///
+/// * `result` - Analysis result to format
+/// * `config` - Configuration for formatting
+/// * `flavor` - Markdown dialect of the code host
+///
+/// # Returns
+///
+/// Formatted markdown string
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::{
+///     config::Config,
+///     output::comment::{CommentFlavor, format_comment_for},
+///     types::{AnalysisResult, AnalysisScope, Summary},
+/// };
+///
+/// let result = AnalysisResult::new(vec![], Summary::default(), AnalysisScope::new());
+/// let output = format_comment_for(&result, &Config::default(), CommentFlavor::Gitlab);
+/// assert!(output.contains("> ✅ **PR size is within limits.**"));
+/// assert!(!output.contains("[!TIP]"));
+/// ```
+pub fn format_comment_for(
+    result: &AnalysisResult,
+    config: &Config,
+    flavor: CommentFlavor,
+) -> String {
     let summary = &result.summary;
 
     let mut output = String::new();
@@ -61,57 +193,33 @@ pub fn format_comment(result: &AnalysisResult, config: &Config) -> String {
     output.push_str("## Rust Diff Analysis\n\n");
 
     if summary.exceeds_limit {
-        output.push_str("> [!CAUTION]\n");
+        push_alert(&mut output, flavor, LimitStatus::Fail);
+        output
+            .push_str("**PR exceeds configured limits.** Consider splitting into smaller PRs.\n");
+        push_quoted_list(&mut output, &describe_violations(result, LimitStatus::Fail));
+    } else if summary.status == LimitStatus::Warn {
+        push_alert(&mut output, flavor, LimitStatus::Warn);
         output.push_str(
-            "> **PR exceeds configured limits.** Consider splitting into smaller PRs.\n",
+            "**PR is approaching configured limits.** Consider keeping further changes for a \
+             follow-up PR.\n",
         );
-
-        let mut exceeded = Vec::new();
-        if summary.total_prod_units() > config.limits.max_prod_units {
-            exceeded.push(format!(
-                "**{}** units (limit: {})",
-                summary.total_prod_units(),
-                config.limits.max_prod_units
-            ));
-        }
-        if summary.weighted_score > config.limits.max_weighted_score {
-            exceeded.push(format!(
-                "**{}** weighted score (limit: {})",
-                summary.weighted_score, config.limits.max_weighted_score
-            ));
-        }
-        if let Some(max_lines) = config.limits.max_prod_lines
-            && summary.prod_lines_added > max_lines
-        {
-            exceeded.push(format!(
-                "**{}** lines added (limit: {})",
-                summary.prod_lines_added, max_lines
-            ));
-        }
-        for (kind, count, limit) in exceeded_per_type_limits(&result.changes, config) {
-            exceeded.push(format!(
-                "**{}** changed units of type `{}` (limit: {})",
-                count, kind, limit
-            ));
-        }
-        if !exceeded.is_empty() {
-            output.push_str(">\n");
-            for item in &exceeded {
-                let _ = writeln!(output, "> - {}", item);
-            }
-        }
+        push_quoted_list(&mut output, &describe_violations(result, LimitStatus::Warn));
     } else {
-        output.push_str("> [!TIP]\n");
-        output.push_str("> **PR size is within limits.** Good job keeping changes focused!\n");
+        push_alert(&mut output, flavor, LimitStatus::Ok);
+        output.push_str("**PR size is within limits.** Good job keeping changes focused!\n");
     }
 
+    let limits = &config.limits;
+    let warn = &limits.warn;
+
     output.push_str("\n<details>\n");
     output.push_str(
         "<summary><strong>Limits</strong> — configured thresholds for this \
          repository</summary>\n\n",
     );
     output.push_str("> *Each metric is compared against its configured maximum. ");
-    output.push_str("If any limit is exceeded, the PR check fails.*\n\n");
+    output.push_str("If any limit is exceeded, the PR check fails; ");
+    output.push_str("crossing a warning threshold only flags the PR.*\n\n");
     output.push_str("| Metric | Value | Limit | Status |\n");
     output.push_str("|--------|------:|------:|:------:|\n");
 
@@ -119,25 +227,53 @@ pub fn format_comment(result: &AnalysisResult, config: &Config) -> String {
         output,
         "| Production Units | {} | {} | {} |",
         summary.total_prod_units(),
-        config.limits.max_prod_units,
-        status_icon(summary.total_prod_units() > config.limits.max_prod_units)
+        limit_cell(Some(limits.max_prod_units), warn.max_prod_units),
+        row_icon(result, LimitKind::MaxProdUnits)
     );
 
     let _ = writeln!(
         output,
         "| Weighted Score | {} | {} | {} |",
         summary.weighted_score,
-        config.limits.max_weighted_score,
-        status_icon(summary.weighted_score > config.limits.max_weighted_score)
+        limit_cell(Some(limits.max_weighted_score), warn.max_weighted_score),
+        row_icon(result, LimitKind::MaxWeightedScore)
     );
 
-    if let Some(max_lines) = config.limits.max_prod_lines {
+    if limits.max_prod_lines.is_some() || warn.max_prod_lines.is_some() {
         let _ = writeln!(
             output,
             "| Lines Added | {} | {} | {} |",
             summary.prod_lines_added,
-            max_lines,
-            status_icon(summary.prod_lines_added > max_lines)
+            limit_cell(limits.max_prod_lines, warn.max_prod_lines),
+            row_icon(result, LimitKind::MaxProdLines)
+        );
+    }
+
+    if limits.min_test_ratio.is_some() || warn.min_test_ratio.is_some() {
+        let value = summary
+            .test_ratio()
+            .map(|ratio| format!("{:.2}", ratio))
+            .unwrap_or_else(|| "-".to_string());
+        let mut limit = match limits.min_test_ratio {
+            Some(minimum) => format!("≥ {:.2}", minimum),
+            None => "-".to_string(),
+        };
+        if let Some(warn_ratio) = warn.min_test_ratio {
+            let _ = write!(limit, " (warn < {:.2})", warn_ratio);
+        }
+        if limits.min_test_ratio_above_prod_lines > 0 {
+            let _ = write!(
+                limit,
+                " above {} lines",
+                limits.min_test_ratio_above_prod_lines
+            );
+        }
+        let _ = writeln!(
+            output,
+            "| Test Ratio | {} | {} | {} |",
+            value,
+            limit,
+            row_icon(result, LimitKind::MinTestRatio)
         );
     }
 
@@ -151,6 +287,12 @@ pub fn format_comment(result: &AnalysisResult, config: &Config) -> String {
          private)\n",
     );
     output.push_str("- **Lines Added**: Raw count of new lines in production code\n");
+    if limits.min_test_ratio.is_some() || warn.min_test_ratio.is_some() {
+        output.push_str(
+            "- **Test Ratio**: Test lines added per production line added; the minimum applies \
+             once production lines exceed the threshold\n",
+        );
+    }
     output.push_str("\n</details>\n");
 
     output.push_str("\n<details>\n");
@@ -182,8 +324,25 @@ pub fn format_comment(result: &AnalysisResult, config: &Config) -> String {
         summary.total_prod_units(),
         summary.test_units
     );
+
+    if !summary.by_type.is_empty() {
+        output.push_str("\n| Code type | Units | Lines added | Lines removed |\n");
+        output.push_str("|-----------|------:|------------:|--------------:|\n");
+        for (code_type, stats) in &summary.by_type {
+            let _ = writeln!(
+                output,
+                "| {} | {} | +{} | -{} |",
+                code_type.label(),
+                stats.units,
+                stats.lines_added,
+                stats.lines_removed
+            );
+        }
+    }
     output.push_str("\n</details>\n");
 
+    format_features_section(&mut output, result);
+
     if config.output.include_details && !result.changes.is_empty() {
         let prod_changes: Vec<_> = result.production_changes().collect();
         let test_changes: Vec<_> = result.test_changes().collect();
@@ -224,7 +383,9 @@ pub fn format_comment(result: &AnalysisResult, config: &Config) -> String {
         }
     }
 
-    format_scope_section(&mut output, result);
+    format_suppressed_section(&mut output, result);
+
+    format_scope_section(&mut output, result, config);
 
     output.push_str("\n---\n");
     output.push_str(
@@ -249,12 +410,90 @@ fn write_change_row(output: &mut String, change: &Change) {
     );
 }
 
-fn format_scope_section(output: &mut String, result: &AnalysisResult) {
+fn format_features_section(output: &mut String, result: &AnalysisResult) {
+    let features = &result.summary.features;
+    if features.is_empty() {
+        return;
+    }
+
+    output.push_str("\n<details>\n");
+    let _ = writeln!(
+        output,
+        "<summary><strong>Features</strong> — production changes gated by {} cargo \
+         features</summary>\n",
+        features.len()
+    );
+    output.push_str(
+        "> *Units under `#[cfg(feature = \"...\")]`, including those inherited from enclosing \
+         modules. A unit gated by several features is listed under each.*\n\n",
+    );
+    output.push_str("| Feature | Units | Lines added | Lines removed |\n");
+    output.push_str("|---------|------:|------------:|--------------:|\n");
+    for (feature, stats) in features {
+        let _ = writeln!(
+            output,
+            "| `{}` | {} | +{} | -{} |",
+            escape_cell(feature),
+            stats.units,
+            stats.lines_added,
+            stats.lines_removed
+        );
+    }
+    output.push_str("\n</details>\n");
+}
+
+fn format_suppressed_section(output: &mut String, result: &AnalysisResult) {
+    let suppressed: Vec<_> = result.suppressed_changes().collect();
+    if suppressed.is_empty() {
+        return;
+    }
+
+    let summary = &result.summary;
+    output.push_str("\n<details>\n");
+    let _ = writeln!(
+        output,
+        "<summary><strong>Suppressed</strong> — {} units (+{} / -{} lines) opted out of \
+         limits</summary>\n",
+        suppressed.len(),
+        summary.suppressed_lines_added,
+        summary.suppressed_lines_removed
+    );
+    output.push_str(
+        "> *Excluded by an inline marker in the source or a configured allowlist entry. Please \
+         check that the exemption is justified.*\n\n",
+    );
+    output.push_str("| File | Unit | Type | Changes | Marker |\n");
+    output.push_str("|------|------|:----:|--------:|--------|\n");
+    for change in suppressed {
+        let span = &change.unit.span;
+        let _ = writeln!(
+            output,
+            "| `{}:{}-{}` | `{}` | {} | +{} -{} | {} |",
+            escape_cell(&change.file_path.display().to_string()),
+            span.start,
+            span.end,
+            escape_cell(&change.unit.qualified_name()),
+            change.unit.kind.as_str(),
+            change.lines_added,
+            change.lines_removed,
+            change
+                .suppression
+                .as_ref()
+                .map(suppression_cell)
+                .unwrap_or_default()
+        );
+    }
+    output.push_str("\n</details>\n");
+}
+
+fn format_scope_section(output: &mut String, result: &AnalysisResult, config: &Config) {
     let scope = &result.scope;
+    let generated = result.summary.type_stats(CodeType::Generated);
 
     if scope.analyzed_files.is_empty()
         && scope.skipped_files.is_empty()
         && scope.exclusion_patterns.is_empty()
+        && generated.units == 0
     {
         return;
     }
@@ -270,6 +509,32 @@ fn format_scope_section(output: &mut String, result: &AnalysisResult) {
         );
     }
 
+    if generated.units > 0 || !scope.generated_files.is_empty() {
+        let counted = if config.limits.count_generated {
+            "counted toward limits"
+        } else {
+            "not counted toward limits"
+        };
+        let _ = writeln!(
+            output,
+            "**Generated code:** {} units (+{} / -{} lines), {}\n",
+            generated.units, generated.lines_added, generated.lines_removed, counted
+        );
+        for path in scope.generated_files.iter().take(MAX_SKIPPED_LISTED) {
+            let _ = writeln!(output, "- `{}`", escape_cell(&path.display().to_string()));
+        }
+        if scope.generated_files.len() > MAX_SKIPPED_LISTED {
+            let _ = writeln!(
+                output,
+                "- …and {} more",
+                scope.generated_files.len() - MAX_SKIPPED_LISTED
+            );
+        }
+        if !scope.generated_files.is_empty() {
+            output.push('\n');
+        }
+    }
+
     if !scope.exclusion_patterns.is_empty() {
         output.push_str("**Excluded patterns:**\n");
         for pattern in &scope.exclusion_patterns {
@@ -280,10 +545,11 @@ fn format_scope_section(output: &mut String, result: &AnalysisResult) {
 
     let non_rust = scope.non_rust_count();
     let ignored = scope.ignored_count();
+    let attributed = scope.attribute_count();
     let deleted = scope.deleted_count();
     let errored = scope.error_count();
 
-    if non_rust > 0 || ignored > 0 || deleted > 0 || errored > 0 {
+    if non_rust > 0 || ignored > 0 || attributed > 0 || deleted > 0 || errored > 0 {
         output.push_str("**Skipped files:**\n");
         if non_rust > 0 {
             let _ = writeln!(output, "- {} non-Rust files", non_rust);
@@ -291,6 +557,9 @@ fn format_scope_section(output: &mut String, result: &AnalysisResult) {
         if ignored > 0 {
             let _ = writeln!(output, "- {} files matched ignore patterns", ignored);
         }
+        if attributed > 0 {
+            let _ = writeln!(output, "- {} files excluded by .gitattributes", attributed);
+        }
         if deleted > 0 {
             let _ = writeln!(output, "- {} deleted files", deleted);
         }
@@ -306,7 +575,9 @@ fn format_scope_section(output: &mut String, result: &AnalysisResult) {
             let reason = match &skipped.reason {
                 ExclusionReason::NonRust => "non-Rust".to_string(),
                 ExclusionReason::IgnorePattern(p) => format!("pattern: {}", escape_cell(p)),
+                ExclusionReason::GitAttribute(a) => format!("gitattributes: {}", escape_cell(a)),
                 ExclusionReason::Deleted => "deleted".to_string(),
+                ExclusionReason::Binary => "binary".to_string(),
                 ExclusionReason::ReadError(e) => format!("read error: {}", escape_cell(e)),
                 ExclusionReason::ParseError(e) => format!("parse error: {}", escape_cell(e)),
             };
@@ -351,7 +622,10 @@ pub fn get_comment_marker() -> &'static str {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{AnalysisScope, Summary};
+    use crate::{
+        classifier::rules::evaluate_limits,
+        types::{AnalysisScope, Summary},
+    };
 
     #[test]
     fn test_format_comment() {
@@ -379,6 +653,41 @@ mod tests {
         assert!(output.contains("PR exceeds configured limits"));
     }
 
+    #[test]
+    fn test_gitlab_flavor_replaces_alerts() {
+        let summary = Summary {
+            exceeds_limit: true,
+            ..Default::default()
+        };
+        let result = AnalysisResult::new(vec![], summary, AnalysisScope::new());
+        let config = Config::default();
+        let output = format_comment_for(&result, &config, CommentFlavor::Gitlab);
+
+        assert!(!output.contains("[!"));
+        assert!(output.contains("> ❌ **PR exceeds configured limits.**"));
+        assert!(output.contains(COMMENT_MARKER));
+    }
+
+    #[test]
+    fn test_warning_status_lists_crossed_thresholds() {
+        let mut config = Config::default();
+        config.limits.warn.max_weighted_score = Some(40);
+
+        let summary = Summary {
+            weighted_score: 55,
+            ..Default::default()
+        };
+        let violations = evaluate_limits(&[], &summary, &config);
+        let result =
+            AnalysisResult::new(vec![], summary, AnalysisScope::new()).with_violations(violations);
+        let output = format_comment(&result, &config);
+
+        assert!(output.contains("[!WARNING]"));
+        assert!(output.contains("**55** weighted score (warning at: 40)"));
+        assert!(output.contains("| Weighted Score | 55 | 100 (warn > 40) | ⚠️ |"));
+        assert!(!output.contains("[!CAUTION]"));
+    }
+
     #[test]
     fn test_get_comment_marker() {
         let marker = get_comment_marker();
@@ -423,18 +732,97 @@ mod tests {
             ..PerTypeLimits::default()
         });
 
-        let summary = Summary {
-            exceeds_limit: true,
+        let changes = vec![make_change(), make_change()];
+        let violations = evaluate_limits(&changes, &Summary::default(), &config);
+        let result = AnalysisResult::new(changes, Summary::default(), AnalysisScope::new())
+            .with_violations(violations);
+        let output = format_comment(&result, &config);
+
+        assert!(output.contains("changed units of type `function` (limit: 1)"));
+    }
+
+    #[test]
+    fn test_test_ratio_row_and_reason() {
+        use crate::types::TypeStats;
+
+        let mut config = Config::default();
+        config.limits.min_test_ratio = Some(0.5);
+        config.limits.min_test_ratio_above_prod_lines = 50;
+
+        let mut summary = Summary {
+            prod_lines_added: 120,
+            test_lines_added: 30,
             ..Default::default()
         };
-        let result = AnalysisResult::new(
-            vec![make_change(), make_change()],
-            summary,
-            AnalysisScope::new(),
+        summary.by_type.insert(
+            CodeType::Test,
+            TypeStats {
+                units: 2,
+                lines_added: 30,
+                lines_removed: 0,
+            },
         );
+        let violations = evaluate_limits(&[], &summary, &config);
+        let result =
+            AnalysisResult::new(vec![], summary, AnalysisScope::new()).with_violations(violations);
         let output = format_comment(&result, &config);
 
-        assert!(output.contains("changed units of type `function` (limit: 1)"));
+        assert!(output.contains("| Test Ratio | 0.25 | ≥ 0.50 above 50 lines | ❌ |"));
+        assert!(output.contains("**0.25** test lines per production line (minimum: 0.50)"));
+
+        let quiet = format_comment(
+            &AnalysisResult::new(vec![], Summary::default(), AnalysisScope::new()),
+            &Config::default(),
+        );
+        assert!(!quiet.contains("Test Ratio"));
+    }
+
+    #[test]
+    fn test_feature_breakdown_listed() {
+        use crate::types::FeatureStats;
+
+        let mut summary = Summary::default();
+        summary.features.insert(
+            "serde".to_string(),
+            FeatureStats {
+                units: 2,
+                lines_added: 14,
+                lines_removed: 3,
+            },
+        );
+        let result = AnalysisResult::new(vec![], summary, AnalysisScope::new());
+        let output = format_comment(&result, &Config::default());
+
+        assert!(output.contains("<strong>Features</strong>"));
+        assert!(output.contains("| `serde` | 2 | +14 | -3 |"));
+
+        let empty = AnalysisResult::new(vec![], Summary::default(), AnalysisScope::new());
+        assert!(!format_comment(&empty, &Config::default()).contains("<strong>Features</strong>"));
+    }
+
+    #[test]
+    fn test_generated_code_shown_in_scope() {
+        use std::path::PathBuf;
+
+        use crate::types::TypeStats;
+
+        let mut scope = AnalysisScope::new();
+        scope.add_analyzed(PathBuf::from("src/proto/api.rs"));
+        scope.add_generated(PathBuf::from("src/proto/api.rs"));
+        let mut summary = Summary::default();
+        summary.by_type.insert(
+            CodeType::Generated,
+            TypeStats {
+                units: 3,
+                lines_added: 40,
+                lines_removed: 0,
+            },
+        );
+        let result = AnalysisResult::new(vec![], summary, scope);
+        let output = format_comment(&result, &Config::default());
+
+        assert!(output.contains("**Generated code:** 3 units (+40 / -0 lines), not counted"));
+        assert!(output.contains("- `src/proto/api.rs`"));
     }
 
     #[test]
@@ -455,4 +843,73 @@ mod tests {
         assert!(output.contains("`file0.txt`"));
         assert!(!output.contains("`file14.txt`"));
     }
+
+    #[test]
+    fn test_suppressed_changes_listed_separately() {
+        use std::path::PathBuf;
+
+        use crate::types::{
+            CodeType, LineSpan, SemanticUnit, SemanticUnitKind, Suppression, Visibility,
+        };
+
+        let unit = SemanticUnit::new(
+            SemanticUnitKind::Function,
+            "opcodes".to_string(),
+            Visibility::Public,
+            LineSpan::new(3, 900),
+            vec![],
+        );
+        let change = Change::new(
+            PathBuf::from("src/vm.rs"),
+            unit,
+            CodeType::Production,
+            850,
+            0,
+        )
+        .with_suppression(Suppression::Comment);
+
+        let summary = Summary {
+            suppressed_units: 1,
+            suppressed_lines_added: 850,
+            ..Default::default()
+        };
+        let result = AnalysisResult::new(vec![change], summary, AnalysisScope::new());
+        let output = format_comment(&result, &Config::default());
+
+        assert!(output.contains("<strong>Suppressed</strong> — 1 units (+850 / -0 lines)"));
+        assert!(output.contains(
+            "| `src/vm.rs:3-900` | `opcodes` | function | +850 -0 | `// rust-diff-analyzer: \
+             ignore-next-item` |"
+        ));
+        assert!(!output.contains("<strong>Production Changes</strong>"));
+    }
+
+    #[test]
+    fn test_code_type_rows_in_summary() {
+        use crate::types::{CodeType, TypeStats};
+
+        let mut summary = Summary::default();
+        summary.by_type.insert(
+            CodeType::Benchmark,
+            TypeStats {
+                units: 2,
+                lines_added: 30,
+                lines_removed: 0,
+            },
+        );
+        summary.by_type.insert(
+            CodeType::Vendored,
+            TypeStats {
+                units: 1,
+                lines_added: 0,
+                lines_removed: 4,
+            },
+        );
+        let result = AnalysisResult::new(vec![], summary, AnalysisScope::new());
+        let output = format_comment(&result, &Config::default());
+
+        assert!(output.contains("| Benchmark | 2 | +30 | -0 |"));
+        assert!(output.contains("| Vendored | 1 | +0 | -4 |"));
+        assert!(!output.contains("| Documentation |"));
+    }
 }
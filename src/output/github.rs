@@ -1,40 +1,354 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+use std::fmt::Write;
+
 use masterror::AppError;
 
 use super::formatter::Formatter;
-use crate::{config::Config, types::AnalysisResult};
+use crate::{
+    classifier::rules::{calculate_weight, contributes_to},
+    config::Config,
+    types::{AnalysisResult, Change, CodeType, LimitStatus},
+};
+
+/// Most notices GitHub displays for a single step
+const MAX_NOTICES: usize = 10;
 
 /// Formatter for GitHub Actions output
 pub struct GithubFormatter;
 
+/// Formatter for GitHub workflow command annotations
+///
+/// Printed in a workflow step, the commands show up inline in the pull
+/// request's "Files changed" tab. Every limit violation is an `::error` or
+/// `::warning` at the first change counted toward it. Up to ten `::notice`
+/// commands point at counted production units: newly added public API
+/// first, then the heaviest contributors to the weighted score.
+pub struct GithubAnnotationsFormatter;
+
 impl Formatter for GithubFormatter {
     fn format(&self, result: &AnalysisResult, _config: &Config) -> Result<String, AppError> {
-        use std::fmt::Write;
+        let mut output = String::new();
+        for (name, value) in step_outputs(result) {
+            let _ = writeln!(output, "{}={}", name, value);
+        }
+        Ok(output)
+    }
+}
 
-        let summary = &result.summary;
+/// Returns the step outputs of an analysis as name and value pairs
+///
+/// # Arguments
+///
+/// * `result` - Analysis result
+///
+/// # Returns
+///
+/// Outputs in the order the `github` format prints them
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::{
+///     output::github::step_outputs,
+///     types::{AnalysisResult, AnalysisScope, Summary},
+/// };
+///
+/// let result = AnalysisResult::new(vec![], Summary::default(), AnalysisScope::new());
+/// let outputs = step_outputs(&result);
+/// assert_eq!(
+///     outputs[0],
+///     ("prod_functions_changed".to_string(), "0".to_string())
+/// );
+/// assert!(outputs.contains(&("status".to_string(), "ok".to_string())));
+/// ```
+pub fn step_outputs(result: &AnalysisResult) -> Vec<(String, String)> {
+    let summary = &result.summary;
+    let mut outputs = vec![
+        ("prod_functions_changed", summary.prod_functions.to_string()),
+        ("prod_structs_changed", summary.prod_structs.to_string()),
+        ("prod_other_changed", summary.prod_other.to_string()),
+        ("test_units_changed", summary.test_units.to_string()),
+        ("prod_lines_added", summary.prod_lines_added.to_string()),
+        ("prod_lines_removed", summary.prod_lines_removed.to_string()),
+        ("test_lines_added", summary.test_lines_added.to_string()),
+        ("test_lines_removed", summary.test_lines_removed.to_string()),
+    ]
+    .into_iter()
+    .map(|(name, value)| (name.to_string(), value))
+    .collect::<Vec<_>>();
+
+    for code_type in CodeType::ALL.iter().filter(|t| !t.is_production()) {
+        let key = code_type.output_key();
+        let stats = summary.type_stats(*code_type);
+        outputs.push((format!("{}_units_changed", key), stats.units.to_string()));
+        outputs.push((
+            format!("{}_lines_added", key),
+            stats.lines_added.to_string(),
+        ));
+        outputs.push((
+            format!("{}_lines_removed", key),
+            stats.lines_removed.to_string(),
+        ));
+    }
+
+    outputs.push((
+        "weighted_score".to_string(),
+        summary.weighted_score.to_string(),
+    ));
+    outputs.push((
+        "exceeds_limit".to_string(),
+        summary.exceeds_limit.to_string(),
+    ));
+    outputs.push(("status".to_string(), summary.status.as_str().to_string()));
+    outputs
+}
+
+/// Formats step outputs for the file named by `GITHUB_OUTPUT`
+///
+/// Every output uses the `name<<delimiter` syntax, so values may span
+/// several lines. The delimiter is chosen so no line of the value equals
+/// it.
+///
+/// # Arguments
+///
+/// * `result` - Analysis result
+///
+/// # Returns
+///
+/// Text to append to the `GITHUB_OUTPUT` file
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::{
+///     output::github::format_step_outputs,
+///     types::{AnalysisResult, AnalysisScope, Summary},
+/// };
+///
+/// let result = AnalysisResult::new(vec![], Summary::default(), AnalysisScope::new());
+/// let output = format_step_outputs(&result);
+/// assert!(output.contains("status<<ghadelimiter\nok\nghadelimiter\n"));
+/// ```
+pub fn format_step_outputs(result: &AnalysisResult) -> String {
+    let mut output = String::new();
+    for (name, value) in step_outputs(result) {
+        push_step_output(&mut output, &name, &value);
+    }
+    output
+}
+
+fn push_step_output(output: &mut String, name: &str, value: &str) {
+    let mut delimiter = "ghadelimiter".to_string();
+    let mut attempt = 0;
+    while value.lines().any(|line| line == delimiter) {
+        attempt += 1;
+        delimiter = format!("ghadelimiter_{}", attempt);
+    }
+    let _ = writeln!(output, "{}<<{}\n{}\n{}", name, delimiter, value, delimiter);
+}
+
+impl Formatter for GithubAnnotationsFormatter {
+    fn format(&self, result: &AnalysisResult, config: &Config) -> Result<String, AppError> {
         let mut output = String::new();
 
-        let _ = writeln!(output, "prod_functions_changed={}", summary.prod_functions);
-        let _ = writeln!(output, "prod_structs_changed={}", summary.prod_structs);
-        let _ = writeln!(output, "prod_other_changed={}", summary.prod_other);
-        let _ = writeln!(output, "test_units_changed={}", summary.test_units);
-        let _ = writeln!(output, "prod_lines_added={}", summary.prod_lines_added);
-        let _ = writeln!(output, "prod_lines_removed={}", summary.prod_lines_removed);
-        let _ = writeln!(output, "test_lines_added={}", summary.test_lines_added);
-        let _ = writeln!(output, "test_lines_removed={}", summary.test_lines_removed);
-        let _ = writeln!(output, "weighted_score={}", summary.weighted_score);
-        let _ = writeln!(output, "exceeds_limit={}", summary.exceeds_limit);
+        for violation in &result.violations {
+            let command = match violation.severity {
+                LimitStatus::Fail => "error",
+                LimitStatus::Warn | LimitStatus::Ok => "warning",
+            };
+            let location = result
+                .changes
+                .iter()
+                .find(|change| contributes_to(change, violation, config));
+            let title = format!("Limit {}", violation.limit.as_str());
+            push_command(
+                &mut output,
+                command,
+                location,
+                &title,
+                &violation.describe_text(),
+            );
+        }
+
+        let counted: Vec<&Change> = result
+            .changes
+            .iter()
+            .filter(|change| {
+                !change.is_suppressed() && config.counts_toward_limits(change.classification)
+            })
+            .collect();
+
+        let mut notices: Vec<(&Change, &str)> = counted
+            .iter()
+            .filter(|change| is_new_public_api(change))
+            .map(|change| (*change, "New public API"))
+            .collect();
+        let mut heaviest: Vec<&Change> = counted
+            .iter()
+            .copied()
+            .filter(|change| !is_new_public_api(change))
+            .collect();
+        heaviest.sort_by_key(|change| {
+            std::cmp::Reverse((calculate_weight(&change.unit, config), change.total_lines()))
+        });
+        notices.extend(
+            heaviest
+                .into_iter()
+                .map(|change| (change, "Weighted score")),
+        );
+
+        for (change, title) in notices.into_iter().take(MAX_NOTICES) {
+            let message = format!(
+                "Production {} `{}` adds {} to the weighted score (+{} -{})",
+                change.unit.kind.as_str(),
+                change.unit.qualified_name(),
+                calculate_weight(&change.unit, config),
+                change.lines_added,
+                change.lines_removed
+            );
+            push_command(&mut output, "notice", Some(change), title, &message);
+        }
 
         Ok(output)
     }
 }
 
+/// Returns whether a change adds a whole public unit
+fn is_new_public_api(change: &Change) -> bool {
+    change.unit.visibility.is_public()
+        && change.lines_removed == 0
+        && change.lines_added >= change.unit.span.len()
+}
+
+fn push_command(
+    output: &mut String,
+    command: &str,
+    location: Option<&Change>,
+    title: &str,
+    message: &str,
+) {
+    let _ = write!(output, "::{} ", command);
+    if let Some(change) = location {
+        let _ = write!(
+            output,
+            "file={},line={},endLine={},",
+            escape_property(&change.file_path.to_string_lossy().replace('\\', "/")),
+            change.unit.span.start,
+            change.unit.span.end
+        );
+    }
+    let _ = writeln!(
+        output,
+        "title={}::{}",
+        escape_property(title),
+        escape_data(message)
+    );
+}
+
+/// Escapes a workflow command message
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escapes a workflow command property value
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
     use super::*;
-    use crate::types::{AnalysisScope, Summary};
+    use crate::{
+        classifier::rules::evaluate_limits,
+        types::{
+            AnalysisScope, LineSpan, SemanticUnit, SemanticUnitKind, Summary, TypeStats,
+            Visibility,
+        },
+    };
+
+    fn change(
+        name: &str,
+        kind: SemanticUnitKind,
+        visibility: Visibility,
+        lines_removed: usize,
+    ) -> Change {
+        Change::new(
+            PathBuf::from("src/lib.rs"),
+            SemanticUnit::new(
+                kind,
+                name.to_string(),
+                visibility,
+                LineSpan::new(3, 6),
+                vec![],
+            ),
+            CodeType::Production,
+            4,
+            lines_removed,
+        )
+    }
+
+    #[test]
+    fn test_annotations_for_violations_and_units() {
+        let mut config = Config::default();
+        config.limits.max_prod_units = 2;
+
+        let changes = vec![
+            change("helper", SemanticUnitKind::Function, Visibility::Private, 1),
+            change("Parser", SemanticUnitKind::Trait, Visibility::Public, 2),
+            change("parse", SemanticUnitKind::Function, Visibility::Public, 0),
+        ];
+        let summary = Summary {
+            prod_functions: 2,
+            prod_other: 1,
+            ..Default::default()
+        };
+        let violations = evaluate_limits(&changes, &summary, &config);
+        let result = AnalysisResult::new(changes, summary, AnalysisScope::new())
+            .with_violations(violations);
+
+        let output = GithubAnnotationsFormatter
+            .format(&result, &config)
+            .expect("format should succeed");
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(
+            lines,
+            [
+                "::error file=src/lib.rs,line=3,endLine=6,title=Limit max_prod_units::3 units \
+                 (limit: 2)",
+                "::notice file=src/lib.rs,line=3,endLine=6,title=New public API::Production \
+                 function `parse` adds 3 to the weighted score (+4 -0)",
+                "::notice file=src/lib.rs,line=3,endLine=6,title=Weighted score::Production \
+                 trait `Parser` adds 4 to the weighted score (+4 -2)",
+                "::notice file=src/lib.rs,line=3,endLine=6,title=Weighted score::Production \
+                 function `helper` adds 1 to the weighted score (+4 -1)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_step_output_delimiter_avoids_value_lines() {
+        let mut output = String::new();
+        push_step_output(&mut output, "report", "first\nghadelimiter\nlast");
+        assert_eq!(
+            output,
+            "report<<ghadelimiter_1\nfirst\nghadelimiter\nlast\nghadelimiter_1\n"
+        );
+    }
+
+    #[test]
+    fn test_annotation_escaping() {
+        let mut output = String::new();
+        push_command(&mut output, "warning", None, "a:b,c", "50%\nnext");
+        assert_eq!(output, "::warning title=a%3Ab%2Cc::50%25%0Anext\n");
+    }
 
     #[test]
     fn test_github_format() {
@@ -51,6 +365,16 @@ mod tests {
                 test_lines_removed: 30,
                 weighted_score: 23,
                 exceeds_limit: false,
+                by_type: [(
+                    CodeType::Benchmark,
+                    TypeStats {
+                        units: 2,
+                        lines_added: 12,
+                        lines_removed: 1,
+                    },
+                )]
+                .into(),
+                ..Default::default()
             },
             AnalysisScope::new(),
         );
@@ -69,8 +393,36 @@ mod tests {
             "prod_lines_removed=20\n",
             "test_lines_added=100\n",
             "test_lines_removed=30\n",
+            "tests_units_changed=0\n",
+            "tests_lines_added=0\n",
+            "tests_lines_removed=0\n",
+            "test_utility_units_changed=0\n",
+            "test_utility_lines_added=0\n",
+            "test_utility_lines_removed=0\n",
+            "bench_units_changed=2\n",
+            "bench_lines_added=12\n",
+            "bench_lines_removed=1\n",
+            "example_units_changed=0\n",
+            "example_lines_added=0\n",
+            "example_lines_removed=0\n",
+            "build_script_units_changed=0\n",
+            "build_script_lines_added=0\n",
+            "build_script_lines_removed=0\n",
+            "generated_units_changed=0\n",
+            "generated_lines_added=0\n",
+            "generated_lines_removed=0\n",
+            "fuzz_units_changed=0\n",
+            "fuzz_lines_added=0\n",
+            "fuzz_lines_removed=0\n",
+            "documentation_units_changed=0\n",
+            "documentation_lines_added=0\n",
+            "documentation_lines_removed=0\n",
+            "vendored_units_changed=0\n",
+            "vendored_lines_added=0\n",
+            "vendored_lines_removed=0\n",
             "weighted_score=23\n",
             "exceeds_limit=false\n",
+            "status=ok\n",
         );
         assert_eq!(output, expected);
     }
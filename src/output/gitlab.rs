@@ -0,0 +1,251 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{collections::HashMap, fmt::Write, path::Path};
+
+use masterror::AppError;
+use serde::Serialize;
+
+use super::{formatter::Formatter, sarif::limit_rule_id};
+use crate::{
+    classifier::rules::contributes_to,
+    config::Config,
+    error::OutputError,
+    types::{AnalysisResult, Change, LimitStatus, LimitViolation},
+};
+
+/// Formatter for GitLab Code Quality reports
+///
+/// Every unit that counts toward a crossed limit becomes an issue located
+/// at the unit, so the merge request widget points at the code that pushes
+/// the change set over budget. Exceeded limits are `major` issues, crossed
+/// warning thresholds `minor` ones. Check names are the SARIF rule IDs,
+/// e.g. `limit/max_prod_units`.
+pub struct GitlabFormatter;
+
+#[derive(Serialize)]
+struct Issue {
+    description: String,
+    check_name: String,
+    fingerprint: String,
+    severity: &'static str,
+    location: Location,
+}
+
+#[derive(Serialize)]
+struct Location {
+    path: String,
+    lines: Lines,
+}
+
+#[derive(Serialize)]
+struct Lines {
+    begin: usize,
+    end: usize,
+}
+
+impl Formatter for GitlabFormatter {
+    fn format(&self, result: &AnalysisResult, config: &Config) -> Result<String, AppError> {
+        let mut issues = Vec::new();
+        for violation in &result.violations {
+            let mut occurrences: HashMap<(&Path, &str, String), usize> = HashMap::new();
+            for change in &result.changes {
+                if !contributes_to(change, violation, config) {
+                    continue;
+                }
+                let key = (
+                    change.file_path.as_path(),
+                    change.unit.kind.as_str(),
+                    change.unit.qualified_name(),
+                );
+                let occurrence = occurrences.entry(key).or_default();
+                issues.push(issue(violation, change, *occurrence));
+                *occurrence += 1;
+            }
+        }
+
+        serde_json::to_string_pretty(&issues).map_err(|e| {
+            AppError::from(OutputError {
+                format: "gitlab".to_string(),
+                message: e.to_string(),
+            })
+        })
+    }
+}
+
+fn issue(violation: &LimitViolation, change: &Change, occurrence: usize) -> Issue {
+    let check_name = limit_rule_id(violation.limit);
+    let path = change.file_path.to_string_lossy().replace('\\', "/");
+    let qualified_name = change.unit.qualified_name();
+    let kind = change.unit.kind.as_str();
+    let crossed = match violation.severity {
+        LimitStatus::Fail => "an exceeded limit",
+        LimitStatus::Warn | LimitStatus::Ok => "a crossed warning threshold",
+    };
+
+    Issue {
+        description: format!(
+            "Production {} `{}` counts toward {}: {}",
+            kind,
+            qualified_name,
+            crossed,
+            violation.describe_text()
+        ),
+        fingerprint: fingerprint(&[
+            &check_name,
+            &path,
+            kind,
+            &qualified_name,
+            &occurrence.to_string(),
+        ]),
+        check_name,
+        severity: match violation.severity {
+            LimitStatus::Fail => "major",
+            LimitStatus::Warn | LimitStatus::Ok => "minor",
+        },
+        location: Location {
+            path,
+            lines: Lines {
+                begin: change.unit.span.start,
+                end: change.unit.span.end,
+            },
+        },
+    }
+}
+
+/// Hashes the parts with 64-bit FNV-1a
+///
+/// Line numbers are left out so an issue keeps its fingerprint when the
+/// unit moves, which lets GitLab match it between the source and target
+/// branch reports. Units sharing a name in one file, such as two
+/// `impl Foo` blocks, are told apart by their occurrence index in file
+/// order instead.
+fn fingerprint(parts: &[&str]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in parts {
+        for byte in part.bytes().chain(std::iter::once(0)) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    let mut hex = String::with_capacity(16);
+    let _ = write!(hex, "{:016x}", hash);
+    hex
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::{
+        classifier::rules::evaluate_limits,
+        types::{
+            AnalysisScope, CodeType, LineSpan, SemanticUnit, SemanticUnitKind, Summary, Visibility,
+        },
+    };
+
+    fn change(name: &str, classification: CodeType, start: usize) -> Change {
+        Change::new(
+            PathBuf::from("src/lib.rs"),
+            SemanticUnit::new(
+                SemanticUnitKind::Function,
+                name.to_string(),
+                Visibility::Public,
+                LineSpan::new(start, start + 6),
+                vec![],
+            ),
+            classification,
+            4,
+            1,
+        )
+    }
+
+    fn report(changes: Vec<Change>, config: &Config) -> serde_json::Value {
+        let summary = Summary {
+            prod_functions: changes
+                .iter()
+                .filter(|c| c.classification.is_production())
+                .count(),
+            ..Default::default()
+        };
+        let violations = evaluate_limits(&changes, &summary, config);
+        let result = AnalysisResult::new(changes, summary, AnalysisScope::new())
+            .with_violations(violations);
+        let output = GitlabFormatter
+            .format(&result, config)
+            .expect("format should succeed");
+        serde_json::from_str(&output).expect("valid JSON")
+    }
+
+    #[test]
+    fn test_issue_per_contributing_unit() {
+        let mut config = Config::default();
+        config.limits.max_prod_units = 1;
+
+        let issues = report(
+            vec![
+                change("handler", CodeType::Production, 3),
+                change("router", CodeType::Production, 12),
+                change("test_handler", CodeType::Test, 20),
+            ],
+            &config,
+        );
+        let issues = issues.as_array().expect("issues array");
+
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0]["check_name"], "limit/max_prod_units");
+        assert_eq!(issues[0]["severity"], "major");
+        assert_eq!(issues[0]["location"]["path"], "src/lib.rs");
+        assert_eq!(issues[0]["location"]["lines"]["begin"], 3);
+        assert_eq!(issues[0]["location"]["lines"]["end"], 9);
+        assert_eq!(
+            issues[0]["description"],
+            "Production function `handler` counts toward an exceeded limit: 2 units (limit: 1)"
+        );
+        assert_ne!(issues[0]["fingerprint"], issues[1]["fingerprint"]);
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_line_numbers() {
+        let mut config = Config::default();
+        config.limits.max_prod_units = 0;
+
+        let before = report(vec![change("handler", CodeType::Production, 3)], &config);
+        let after = report(vec![change("handler", CodeType::Production, 40)], &config);
+
+        assert_eq!(before[0]["fingerprint"], after[0]["fingerprint"]);
+        assert_eq!(
+            before[0]["fingerprint"]
+                .as_str()
+                .expect("fingerprint string")
+                .len(),
+            16
+        );
+    }
+
+    #[test]
+    fn test_empty_report_within_limits() {
+        let issues = report(
+            vec![change("handler", CodeType::Production, 3)],
+            &Config::default(),
+        );
+        assert_eq!(issues, serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_same_name_units_get_distinct_fingerprints() {
+        let mut config = Config::default();
+        config.limits.max_prod_units = 0;
+
+        let issues = report(
+            vec![
+                change("handler", CodeType::Production, 3),
+                change("handler", CodeType::Production, 20),
+            ],
+            &config,
+        );
+        assert_eq!(issues.as_array().expect("issues array").len(), 2);
+        assert_ne!(issues[0]["fingerprint"], issues[1]["fingerprint"]);
+    }
+}
@@ -0,0 +1,95 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::fmt::Write;
+
+use masterror::AppError;
+
+use super::formatter::Formatter;
+use crate::{
+    config::Config,
+    types::{AnalysisResult, LimitStatus},
+};
+
+/// Formatter for human-readable terminal output
+pub struct HumanFormatter;
+
+impl Formatter for HumanFormatter {
+    fn format(&self, result: &AnalysisResult, _config: &Config) -> Result<String, AppError> {
+        let summary = &result.summary;
+        let mut output = String::new();
+
+        output.push_str("=== Rust Diff Analysis ===\n\n");
+
+        output.push_str("Production:\n");
+        let _ = writeln!(output, "  Functions: {}", summary.prod_functions);
+        let _ = writeln!(output, "  Structs: {}", summary.prod_structs);
+        let _ = writeln!(output, "  Other: {}", summary.prod_other);
+        let _ = writeln!(
+            output,
+            "  Lines: +{} -{}",
+            summary.prod_lines_added, summary.prod_lines_removed
+        );
+
+        output.push_str("\nTest:\n");
+        let _ = writeln!(output, "  Units: {}", summary.test_units);
+        let _ = writeln!(
+            output,
+            "  Lines: +{} -{}",
+            summary.test_lines_added, summary.test_lines_removed
+        );
+
+        if !summary.by_type.is_empty() {
+            output.push_str("\nBy type:\n");
+            for (code_type, stats) in &summary.by_type {
+                let _ = writeln!(
+                    output,
+                    "  {}: {} units, +{} -{}",
+                    code_type.label(),
+                    stats.units,
+                    stats.lines_added,
+                    stats.lines_removed
+                );
+            }
+        }
+
+        if summary.suppressed_units > 0 {
+            output.push_str("\nSuppressed:\n");
+            let _ = writeln!(output, "  Units: {}", summary.suppressed_units);
+            let _ = writeln!(
+                output,
+                "  Lines: +{} -{}",
+                summary.suppressed_lines_added, summary.suppressed_lines_removed
+            );
+        }
+
+        let _ = writeln!(output, "\nWeighted score: {}", summary.weighted_score);
+
+        if summary.exceeds_limit {
+            output.push_str("\nLIMIT EXCEEDED\n");
+        } else if summary.status == LimitStatus::Warn {
+            output.push_str("\nLIMIT WARNING\n");
+        }
+
+        if !result.changes.is_empty() {
+            output.push_str("\nChanges:\n");
+            for change in &result.changes {
+                let _ = write!(
+                    output,
+                    "  - {} ({}) in {} [+{} -{}]",
+                    change.unit.name,
+                    change.unit.kind.as_str(),
+                    change.file_path.display(),
+                    change.lines_added,
+                    change.lines_removed
+                );
+                if let Some(suppression) = &change.suppression {
+                    let _ = write!(output, " (suppressed: {})", suppression.describe());
+                }
+                output.push('\n');
+            }
+        }
+
+        Ok(output)
+    }
+}
@@ -5,26 +5,59 @@ use masterror::AppError;
 use serde::Serialize;
 
 use super::formatter::Formatter;
-use crate::{config::Config, error::OutputError, types::AnalysisResult};
+use crate::{
+    config::Config,
+    error::OutputError,
+    types::{
+        AnalysisResult, AnalysisScope, ClassificationReason, LimitCheck, LimitStatus,
+        LimitViolation, LineSpan, Suppression,
+    },
+};
 
 /// Formatter for JSON output
+///
+/// Emits the whole analysis: the summary, the analysis scope, every
+/// configured limit with its thresholds and outcome, the crossed limits
+/// and, unless `output.include_details` is off, each change with its span
+/// and qualified name.
 pub struct JsonFormatter;
 
 #[derive(Serialize)]
 struct JsonOutput<'a> {
     summary: &'a crate::types::Summary,
+    scope: &'a AnalysisScope,
+    limits: Vec<JsonLimit<'a>>,
+    exceeded_limits: Vec<String>,
+    violations: &'a [LimitViolation],
     changes: Vec<JsonChange<'a>>,
 }
 
+#[derive(Serialize)]
+struct JsonLimit<'a> {
+    name: String,
+    #[serde(flatten)]
+    check: &'a LimitCheck,
+}
+
 #[derive(Serialize)]
 struct JsonChange<'a> {
     file: String,
     unit: &'a str,
+    qualified_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    impl_name: Option<&'a str>,
+    module_path: &'a [String],
     kind: &'a str,
     visibility: &'a str,
+    span: LineSpan,
+    attributes: &'a [String],
     classification: &'a str,
     lines_added: usize,
     lines_removed: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<&'a ClassificationReason>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suppression: Option<&'a Suppression>,
 }
 
 impl Formatter for JsonFormatter {
@@ -36,19 +69,44 @@ impl Formatter for JsonFormatter {
                 .map(|c| JsonChange {
                     file: c.file_path.to_string_lossy().to_string(),
                     unit: &c.unit.name,
+                    qualified_name: c.unit.qualified_name(),
+                    impl_name: c.unit.impl_name.as_deref(),
+                    module_path: &c.unit.module_path,
                     kind: c.unit.kind.as_str(),
                     visibility: c.unit.visibility.as_str(),
+                    span: c.unit.span,
+                    attributes: &c.unit.attributes,
                     classification: c.classification.as_str(),
                     lines_added: c.lines_added,
                     lines_removed: c.lines_removed,
+                    reason: c.reason.as_ref(),
+                    suppression: c.suppression.as_ref(),
                 })
                 .collect()
         } else {
             vec![]
         };
 
+        let limits: Vec<JsonLimit> = result
+            .checks
+            .iter()
+            .map(|check| JsonLimit {
+                name: check.name(),
+                check,
+            })
+            .collect();
+        let exceeded_limits = limits
+            .iter()
+            .filter(|limit| limit.check.status == LimitStatus::Fail)
+            .map(|limit| limit.name.clone())
+            .collect();
+
         let output = JsonOutput {
             summary: &result.summary,
+            scope: &result.scope,
+            limits,
+            exceeded_limits,
+            violations: &result.violations,
             changes,
         };
 
@@ -81,6 +139,7 @@ mod tests {
                 test_lines_removed: 20,
                 weighted_score: 15,
                 exceeds_limit: false,
+                ..Default::default()
             },
             AnalysisScope::new(),
         );
@@ -93,4 +152,206 @@ mod tests {
         assert!(output.contains("\"prod_functions\": 3"));
         assert!(output.contains("\"weighted_score\": 15"));
     }
+
+    #[test]
+    fn test_json_feature_breakdown() {
+        use crate::types::FeatureStats;
+
+        let mut summary = Summary::default();
+        summary.features.insert(
+            "mock".to_string(),
+            FeatureStats {
+                units: 1,
+                lines_added: 5,
+                lines_removed: 0,
+            },
+        );
+        let result = AnalysisResult::new(vec![], summary, AnalysisScope::new());
+        let output = JsonFormatter
+            .format(&result, &Config::default())
+            .expect("format should succeed");
+
+        let value: serde_json::Value = serde_json::from_str(&output).expect("valid json");
+        assert_eq!(value["summary"]["features"]["mock"]["units"], 1);
+        assert_eq!(value["summary"]["features"]["mock"]["lines_added"], 5);
+    }
+
+    #[test]
+    fn test_json_type_breakdown() {
+        use crate::types::{CodeType, TypeStats};
+
+        let mut summary = Summary::default();
+        summary.by_type.insert(
+            CodeType::Example,
+            TypeStats {
+                units: 2,
+                lines_added: 9,
+                lines_removed: 1,
+            },
+        );
+        let result = AnalysisResult::new(vec![], summary, AnalysisScope::new());
+        let output = JsonFormatter
+            .format(&result, &Config::default())
+            .expect("format should succeed");
+
+        let value: serde_json::Value = serde_json::from_str(&output).expect("valid json");
+        assert_eq!(value["summary"]["by_type"]["Example"]["units"], 2);
+        assert_eq!(value["summary"]["by_type"]["Example"]["lines_removed"], 1);
+    }
+
+    #[test]
+    fn test_json_change_reason() {
+        use std::path::PathBuf;
+
+        use crate::types::{
+            Change, CodeType, LineSpan, SemanticUnit, SemanticUnitKind, Visibility,
+        };
+
+        let unit = SemanticUnit::new(
+            SemanticUnitKind::Function,
+            "helper".to_string(),
+            Visibility::Private,
+            LineSpan::new(1, 3),
+            vec!["cfg_test".to_string()],
+        );
+        let change = Change::new(
+            PathBuf::from("src/lib.rs"),
+            unit,
+            CodeType::TestUtility,
+            3,
+            0,
+        )
+        .with_reason(ClassificationReason::Rule {
+            rule: "test-module".to_string(),
+            matched: "has attribute `cfg_test`".to_string(),
+        });
+        let result = AnalysisResult::new(vec![change], Summary::default(), AnalysisScope::new());
+        let output = JsonFormatter
+            .format(&result, &Config::default())
+            .expect("format should succeed");
+
+        let value: serde_json::Value = serde_json::from_str(&output).expect("valid json");
+        let reason = &value["changes"][0]["reason"];
+        assert_eq!(reason["source"], "rule");
+        assert_eq!(reason["rule"], "test-module");
+        assert_eq!(reason["matched"], "has attribute `cfg_test`");
+    }
+
+    #[test]
+    fn test_json_lists_violations() {
+        use crate::types::{LimitKind, LimitScope, LimitStatus, SemanticUnitKind};
+
+        let result = AnalysisResult::new(vec![], Summary::default(), AnalysisScope::new())
+            .with_violations(vec![LimitViolation::new(
+                LimitKind::PerType,
+                4.0,
+                3.0,
+                LimitScope::UnitKind(SemanticUnitKind::Struct),
+                LimitStatus::Warn,
+            )]);
+        let output = JsonFormatter
+            .format(&result, &Config::default())
+            .expect("format should succeed");
+        let value: serde_json::Value = serde_json::from_str(&output).expect("valid json");
+
+        let violation = &value["violations"][0];
+        assert_eq!(violation["limit"], "per_type");
+        assert_eq!(violation["scope"]["unit_kind"], "Struct");
+        assert_eq!(violation["severity"], "warn");
+        assert_eq!(value["summary"]["status"], "warn");
+    }
+
+    #[test]
+    fn test_json_complete_result() {
+        use std::path::PathBuf;
+
+        use crate::{
+            classifier::rules::check_limits,
+            types::{
+                Change, CodeType, ExclusionReason, SemanticUnit, SemanticUnitKind, Visibility,
+            },
+        };
+
+        let mut config = Config::default();
+        config.limits.max_prod_units = 1;
+        config.limits.warn.max_weighted_score = Some(5);
+
+        let unit = |name: &str, start: usize| {
+            SemanticUnit::with_impl(
+                SemanticUnitKind::Function,
+                name.to_string(),
+                "Parser".to_string(),
+                Visibility::Public,
+                LineSpan::new(start, start + 4),
+                vec!["inline".to_string()],
+            )
+            .with_module_path(vec!["config".to_string()])
+        };
+        let changes = vec![
+            Change::new(
+                PathBuf::from("src/config.rs"),
+                unit("new", 3),
+                CodeType::Production,
+                5,
+                0,
+            ),
+            Change::new(
+                PathBuf::from("src/config.rs"),
+                unit("parse", 10),
+                CodeType::Production,
+                2,
+                1,
+            ),
+        ];
+        let summary = Summary {
+            prod_functions: 2,
+            weighted_score: 6,
+            ..Default::default()
+        };
+        let checks = check_limits(&changes, &summary, &config);
+        let mut scope = AnalysisScope::new();
+        scope.add_analyzed(PathBuf::from("src/config.rs"));
+        scope.add_skipped(PathBuf::from("assets/logo.png"), ExclusionReason::Binary);
+        let result = AnalysisResult::new(changes, summary, scope).with_checks(checks);
+
+        let output = JsonFormatter
+            .format(&result, &Config::default())
+            .expect("format should succeed");
+        let value: serde_json::Value = serde_json::from_str(&output).expect("valid json");
+
+        assert_eq!(value["scope"]["analyzed_files"][0], "src/config.rs");
+        assert_eq!(
+            value["scope"]["skipped_files"][0]["path"],
+            "assets/logo.png"
+        );
+
+        let change = &value["changes"][1];
+        assert_eq!(change["qualified_name"], "Parser::parse");
+        assert_eq!(change["module_path"][0], "config");
+        assert_eq!(change["impl_name"], "Parser");
+        assert_eq!(change["span"]["start"], 10);
+        assert_eq!(change["span"]["end"], 14);
+        assert_eq!(change["attributes"][0], "inline");
+
+        let limits = value["limits"].as_array().expect("limits array");
+        let units = limits
+            .iter()
+            .find(|limit| limit["name"] == "max_prod_units")
+            .expect("max_prod_units check");
+        assert_eq!(units["actual"], 2.0);
+        assert_eq!(units["threshold"], 1.0);
+        assert_eq!(units["status"], "fail");
+        let score = limits
+            .iter()
+            .find(|limit| limit["name"] == "max_weighted_score")
+            .expect("max_weighted_score check");
+        assert_eq!(score["warn"], 5.0);
+        assert_eq!(score["status"], "warn");
+
+        assert_eq!(
+            value["exceeded_limits"],
+            serde_json::json!(["max_prod_units"])
+        );
+        assert_eq!(value["violations"][0]["limit"], "max_prod_units");
+    }
 }
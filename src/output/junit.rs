@@ -0,0 +1,233 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::fmt::Write;
+
+use masterror::AppError;
+
+use super::formatter::Formatter;
+use crate::{
+    config::Config,
+    types::{AnalysisResult, LimitCheck, LimitStatus, LimitValue},
+};
+
+const SUITE_NAME: &str = "rust-diff-analyzer";
+const CASE_CLASSNAME: &str = "rust-diff-analyzer.limits";
+
+/// Formatter for JUnit XML reports
+///
+/// Every configured limit becomes a testcase named after its key (e.g.
+/// `max_prod_units`, `per_type.trait`) that fails when the limit is
+/// exceeded. Crossed warning thresholds pass and are noted in the
+/// testcase output, limits that do not apply to the change set are
+/// skipped, and the changed units are listed in the suite `system-out`.
+pub struct JunitFormatter;
+
+impl Formatter for JunitFormatter {
+    fn format(&self, result: &AnalysisResult, config: &Config) -> Result<String, AppError> {
+        let checks = &result.checks;
+        let failures = checks
+            .iter()
+            .filter(|c| c.status == LimitStatus::Fail)
+            .count();
+        let skipped = checks.iter().filter(|c| c.actual.is_none()).count();
+        let counts = format!(
+            "tests=\"{}\" failures=\"{}\" skipped=\"{}\"",
+            checks.len(),
+            failures,
+            skipped
+        );
+
+        let mut output = String::new();
+        output.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(output, "<testsuites name=\"{}\" {}>", SUITE_NAME, counts);
+        let _ = writeln!(output, "  <testsuite name=\"limits\" {}>", counts);
+
+        for check in checks {
+            push_testcase(&mut output, check, result, config);
+        }
+
+        if !result.changes.is_empty() {
+            output.push_str("    <system-out>");
+            for change in &result.changes {
+                let mut line = format!(
+                    "{}:{}-{} {} {} [{}] +{} -{}",
+                    change.file_path.display(),
+                    change.unit.span.start,
+                    change.unit.span.end,
+                    change.unit.kind.as_str(),
+                    change.unit.qualified_name(),
+                    change.classification.as_str(),
+                    change.lines_added,
+                    change.lines_removed
+                );
+                if let Some(suppression) = &change.suppression {
+                    let _ = write!(line, " (suppressed: {})", suppression.describe());
+                }
+                output.push('\n');
+                output.push_str(&escape(&line));
+            }
+            output.push_str("\n</system-out>\n");
+        }
+
+        output.push_str("  </testsuite>\n");
+        output.push_str("</testsuites>\n");
+
+        Ok(output)
+    }
+}
+
+fn push_testcase(
+    output: &mut String,
+    check: &LimitCheck,
+    result: &AnalysisResult,
+    config: &Config,
+) {
+    let _ = write!(
+        output,
+        "    <testcase name=\"{}\" classname=\"{}\"",
+        escape(&check.name()),
+        CASE_CLASSNAME
+    );
+
+    let Some(actual) = check.actual else {
+        let reason = if result.summary.prod_lines_added == 0 {
+            "no production lines added".to_string()
+        } else {
+            format!(
+                "applies above {} production lines added",
+                config.limits.min_test_ratio_above_prod_lines
+            )
+        };
+        let _ = writeln!(
+            output,
+            ">\n      <skipped message=\"{}\"/>",
+            escape(&reason)
+        );
+        output.push_str("    </testcase>\n");
+        return;
+    };
+
+    let violation = check.violation();
+    match (check.status, &violation) {
+        (LimitStatus::Fail, Some(violation)) => {
+            let _ = writeln!(
+                output,
+                ">\n      <failure message=\"{}\" type=\"limit\">{}</failure>",
+                escape(&violation.describe_text()),
+                escape(&details(check, actual))
+            );
+        }
+        (LimitStatus::Warn, Some(violation)) => {
+            let _ = writeln!(
+                output,
+                ">\n      <system-out>warning: {}</system-out>",
+                escape(&violation.describe_text())
+            );
+        }
+        _ => {
+            output.push_str("/>\n");
+            return;
+        }
+    }
+    output.push_str("    </testcase>\n");
+}
+
+/// Lists the measured value and the configured thresholds of a check
+fn details(check: &LimitCheck, actual: LimitValue) -> String {
+    let value = |value: LimitValue| {
+        if check.limit.is_minimum() {
+            format!("{:.2}", value.get())
+        } else {
+            format!("{:.0}", value.get())
+        }
+    };
+    let bound = if check.limit.is_minimum() {
+        "minimum"
+    } else {
+        "limit"
+    };
+
+    let mut details = format!("actual: {}", value(actual));
+    if let Some(threshold) = check.threshold {
+        let _ = write!(details, "\n{}: {}", bound, value(threshold));
+    }
+    if let Some(warn) = check.warn {
+        let _ = write!(details, "\nwarning: {}", value(warn));
+    }
+    details
+}
+
+/// Escapes text for XML attributes and character data
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::{
+        classifier::rules::check_limits,
+        types::{
+            AnalysisScope, Change, CodeType, LineSpan, SemanticUnit, SemanticUnitKind, Summary,
+            Visibility,
+        },
+    };
+
+    #[test]
+    fn test_junit_testcase_per_configured_limit() {
+        let mut config = Config::default();
+        config.limits.max_prod_units = 0;
+        config.limits.warn.max_weighted_score = Some(1);
+        config.limits.min_test_ratio = Some(0.5);
+
+        let changes = vec![Change::new(
+            PathBuf::from("src/lib.rs"),
+            SemanticUnit::new(
+                SemanticUnitKind::Function,
+                "parse<T>".to_string(),
+                Visibility::Public,
+                LineSpan::new(3, 9),
+                vec![],
+            ),
+            CodeType::Production,
+            4,
+            0,
+        )];
+        let summary = Summary {
+            prod_functions: 1,
+            weighted_score: 3,
+            ..Default::default()
+        };
+        let checks = check_limits(&changes, &summary, &config);
+        let result =
+            AnalysisResult::new(changes, summary, AnalysisScope::new()).with_checks(checks);
+
+        let output = JunitFormatter
+            .format(&result, &config)
+            .expect("format should succeed");
+
+        assert!(output.contains(
+            "<testsuites name=\"rust-diff-analyzer\" tests=\"3\" failures=\"1\" skipped=\"1\">"
+        ));
+        assert!(output.contains(
+            "<failure message=\"1 units (limit: 0)\" type=\"limit\">actual: 1\nlimit: 0</failure>"
+        ));
+        assert!(
+            output.contains("<system-out>warning: 3 weighted score (warning at: 1)</system-out>")
+        );
+        assert!(output.contains("<skipped message=\"no production lines added\"/>"));
+        assert!(output.contains("src/lib.rs:3-9 function parse&lt;T&gt; [production] +4 -0"));
+
+        let relaxed = JunitFormatter
+            .format(&result, &Config::default())
+            .expect("format should succeed");
+        assert!(relaxed.contains("failures=\"1\""));
+    }
+}
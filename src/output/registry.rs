@@ -0,0 +1,293 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{collections::BTreeMap, fmt, sync::Arc};
+
+use masterror::AppError;
+
+use super::{
+    comment::{CommentFormatter, GitlabCommentFormatter},
+    formatter::Formatter,
+    github::{GithubAnnotationsFormatter, GithubFormatter},
+    gitlab::GitlabFormatter,
+    human::HumanFormatter,
+    json::JsonFormatter,
+    junit::JunitFormatter,
+    sarif::SarifFormatter,
+};
+use crate::{
+    config::{Config, OutputFormat},
+    error::OutputError,
+    types::AnalysisResult,
+};
+
+/// Formatters selectable by name
+///
+/// The default registry holds the built-in formats (`github`, `json`,
+/// `human`, `comment`, `sarif`, `junit`, `github-annotations`, `gitlab`,
+/// `gitlab-comment`).
+/// Library users can register further formatters, or replace a built-in one
+/// by registering under its name.
+#[derive(Clone)]
+pub struct FormatterRegistry {
+    formatters: BTreeMap<String, Arc<dyn Formatter + Send + Sync>>,
+}
+
+impl FormatterRegistry {
+    /// Creates a registry without any formatters
+    ///
+    /// # Returns
+    ///
+    /// Empty registry
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::output::FormatterRegistry;
+    ///
+    /// assert_eq!(FormatterRegistry::empty().names().count(), 0);
+    /// ```
+    pub fn empty() -> Self {
+        Self {
+            formatters: BTreeMap::new(),
+        }
+    }
+
+    /// Registers a formatter under a name
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name used to select the formatter
+    /// * `formatter` - Formatter implementation
+    ///
+    /// # Returns
+    ///
+    /// `true` if a formatter with the same name was replaced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{
+    ///     AppError,
+    ///     config::Config,
+    ///     output::{Formatter, FormatterRegistry},
+    ///     types::AnalysisResult,
+    /// };
+    ///
+    /// struct Score;
+    ///
+    /// impl Formatter for Score {
+    ///     fn format(&self, result: &AnalysisResult, _config: &Config) -> Result<String, AppError> {
+    ///         Ok(result.summary.weighted_score.to_string())
+    ///     }
+    /// }
+    ///
+    /// let mut registry = FormatterRegistry::default();
+    /// assert!(!registry.register("score", Score));
+    /// assert!(registry.get("score").is_some());
+    /// ```
+    pub fn register<F>(&mut self, name: impl Into<String>, formatter: F) -> bool
+    where
+        F: Formatter + Send + Sync + 'static,
+    {
+        self.formatters
+            .insert(name.into(), Arc::new(formatter))
+            .is_some()
+    }
+
+    /// Returns the formatter registered under a name
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Formatter name
+    ///
+    /// # Returns
+    ///
+    /// Formatter or `None` if the name is not registered
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::output::FormatterRegistry;
+    ///
+    /// let registry = FormatterRegistry::default();
+    /// assert!(registry.get("json").is_some());
+    /// assert!(registry.get("xml").is_none());
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&(dyn Formatter + Send + Sync)> {
+        self.formatters.get(name).map(Arc::as_ref)
+    }
+
+    /// Returns the formatter registered under a name, or an error
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Formatter name
+    ///
+    /// # Returns
+    ///
+    /// Formatter or error
+    ///
+    /// # Errors
+    ///
+    /// Returns error listing the registered names if `name` is unknown
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::output::FormatterRegistry;
+    ///
+    /// let registry = FormatterRegistry::default();
+    /// assert!(registry.require("comment").is_ok());
+    /// assert!(registry.require("xml").is_err());
+    /// ```
+    pub fn require(&self, name: &str) -> Result<&(dyn Formatter + Send + Sync), AppError> {
+        self.get(name).ok_or_else(|| {
+            AppError::from(OutputError {
+                format: name.to_string(),
+                message: format!(
+                    "unknown format, expected one of: {}",
+                    self.names().collect::<Vec<_>>().join(", ")
+                ),
+            })
+        })
+    }
+
+    /// Returns registered formatter names in alphabetical order
+    ///
+    /// # Returns
+    ///
+    /// Iterator over names
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::output::FormatterRegistry;
+    ///
+    /// let registry = FormatterRegistry::default();
+    /// let names: Vec<_> = registry.names().collect();
+    /// assert_eq!(
+    ///     names,
+    ///     [
+    ///         "comment",
+    ///         "github",
+    ///         "github-annotations",
+    ///         "gitlab",
+    ///         "gitlab-comment",
+    ///         "human",
+    ///         "json",
+    ///         "junit",
+    ///         "sarif"
+    ///     ]
+    /// );
+    /// ```
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.formatters.keys().map(String::as_str)
+    }
+
+    /// Formats a result with the formatter registered under a name
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Formatter name
+    /// * `result` - Analysis result to format
+    /// * `config` - Configuration
+    ///
+    /// # Returns
+    ///
+    /// Formatted string or error
+    ///
+    /// # Errors
+    ///
+    /// Returns error if no formatter is registered under the name or
+    /// formatting fails
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::{
+    ///     config::Config,
+    ///     output::FormatterRegistry,
+    ///     types::{AnalysisResult, AnalysisScope, Summary},
+    /// };
+    ///
+    /// let result = AnalysisResult::new(vec![], Summary::default(), AnalysisScope::new());
+    /// let registry = FormatterRegistry::default();
+    /// let config = Config::default();
+    /// assert!(registry.format("human", &result, &config).is_ok());
+    /// assert!(registry.format("xml", &result, &config).is_err());
+    /// ```
+    pub fn format(
+        &self,
+        name: &str,
+        result: &AnalysisResult,
+        config: &Config,
+    ) -> Result<String, AppError> {
+        self.require(name)?.format(result, config)
+    }
+}
+
+impl Default for FormatterRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry.register(OutputFormat::Github.as_str(), GithubFormatter);
+        registry.register(OutputFormat::Json.as_str(), JsonFormatter);
+        registry.register(OutputFormat::Human.as_str(), HumanFormatter);
+        registry.register(OutputFormat::Comment.as_str(), CommentFormatter);
+        registry.register(OutputFormat::Sarif.as_str(), SarifFormatter);
+        registry.register(OutputFormat::Junit.as_str(), JunitFormatter);
+        registry.register(
+            OutputFormat::GithubAnnotations.as_str(),
+            GithubAnnotationsFormatter,
+        );
+        registry.register(OutputFormat::Gitlab.as_str(), GitlabFormatter);
+        registry.register(OutputFormat::GitlabComment.as_str(), GitlabCommentFormatter);
+        registry
+    }
+}
+
+impl fmt::Debug for FormatterRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{AnalysisScope, Summary};
+
+    struct Fixed(&'static str);
+
+    impl Formatter for Fixed {
+        fn format(&self, _result: &AnalysisResult, _config: &Config) -> Result<String, AppError> {
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn test_registered_formatter_replaces_builtin() {
+        let result = AnalysisResult::new(vec![], Summary::default(), AnalysisScope::new());
+        let config = Config::default();
+
+        let mut registry = FormatterRegistry::default();
+        assert!(registry.register("github", Fixed("custom")));
+        assert_eq!(
+            registry.format("github", &result, &config).unwrap(),
+            "custom"
+        );
+
+        let error = registry
+            .format("xml", &result, &config)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains(
+                "comment, github, github-annotations, gitlab, gitlab-comment, human, json, \
+                 junit, sarif"
+            ),
+            "{}",
+            error
+        );
+    }
+}
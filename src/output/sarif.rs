@@ -0,0 +1,462 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::path::Path;
+
+use masterror::AppError;
+use serde::Serialize;
+
+use super::formatter::Formatter;
+use crate::{
+    classifier::rules::contributes_to,
+    config::Config,
+    error::OutputError,
+    types::{
+        AnalysisResult, Change, LimitKind, LimitScope, LimitStatus, LimitValue, LimitViolation,
+        Suppression,
+    },
+};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const INFORMATION_URI: &str = "https://github.com/RAprogramm/rust-prod-diff-checker";
+
+/// Rule ID of results reporting a changed production unit
+pub const PRODUCTION_CHANGE_RULE: &str = "production-change";
+
+/// Limits in rule order; the rule index of a limit is its position plus one
+const LIMIT_RULES: [LimitKind; 5] = [
+    LimitKind::MaxProdUnits,
+    LimitKind::MaxWeightedScore,
+    LimitKind::MaxProdLines,
+    LimitKind::PerType,
+    LimitKind::MinTestRatio,
+];
+
+/// Formatter for SARIF 2.1.0 output, as consumed by code scanning
+///
+/// Every production change becomes a `note` located at the changed unit,
+/// and every limit violation an `error` or `warning` located at the first
+/// change that counts toward it, or at the first line of the first analyzed
+/// file when no change does. Code scanning rejects results without a
+/// location, so a violation with neither is left out. Rule IDs are `production-change` and
+/// `limit/<limits key>`, e.g. `limit/max_prod_units`.
+pub struct SarifFormatter;
+
+#[derive(Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [Run<'a>; 1],
+}
+
+#[derive(Serialize)]
+struct Run<'a> {
+    tool: Tool,
+    results: Vec<SarifResult<'a>>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<Rule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    id: String,
+    name: String,
+    short_description: Message,
+    default_configuration: RuleConfiguration,
+}
+
+#[derive(Serialize)]
+struct RuleConfiguration {
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    markdown: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult<'a> {
+    rule_id: String,
+    rule_index: usize,
+    level: &'static str,
+    message: Message,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suppressions: Vec<SarifSuppression>,
+    properties: Properties<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    end_line: usize,
+}
+
+#[derive(Serialize)]
+struct SarifSuppression {
+    kind: &'static str,
+    justification: String,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Properties<'a> {
+    #[serde(rename_all = "camelCase")]
+    Change {
+        kind: &'a str,
+        classification: &'a str,
+        qualified_name: String,
+        lines_added: usize,
+        lines_removed: usize,
+    },
+    Violation {
+        actual: LimitValue,
+        threshold: LimitValue,
+        scope: &'a LimitScope,
+    },
+}
+
+impl Formatter for SarifFormatter {
+    fn format(&self, result: &AnalysisResult, config: &Config) -> Result<String, AppError> {
+        let mut results: Vec<SarifResult> = result
+            .changes
+            .iter()
+            .filter(|change| change.classification.is_production())
+            .map(change_result)
+            .collect();
+        results.extend(
+            result
+                .violations
+                .iter()
+                .filter_map(|violation| violation_result(violation, result, config)),
+        );
+
+        let log = SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: [Run {
+                tool: Tool {
+                    driver: Driver {
+                        name: env!("CARGO_PKG_NAME"),
+                        version: env!("CARGO_PKG_VERSION"),
+                        information_uri: INFORMATION_URI,
+                        rules: rules(),
+                    },
+                },
+                results,
+            }],
+        };
+
+        serde_json::to_string_pretty(&log).map_err(|e| {
+            AppError::from(OutputError {
+                format: "sarif".to_string(),
+                message: e.to_string(),
+            })
+        })
+    }
+}
+
+/// Returns the rule ID of a limit
+///
+/// # Arguments
+///
+/// * `limit` - Limit kind
+///
+/// # Returns
+///
+/// Rule ID such as `limit/max_prod_units`
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::{output::sarif::limit_rule_id, types::LimitKind};
+///
+/// assert_eq!(limit_rule_id(LimitKind::PerType), "limit/per_type");
+/// ```
+pub fn limit_rule_id(limit: LimitKind) -> String {
+    format!("limit/{}", limit.as_str())
+}
+
+fn rules() -> Vec<Rule> {
+    let mut rules = vec![Rule {
+        id: PRODUCTION_CHANGE_RULE.to_string(),
+        name: "ProductionChange".to_string(),
+        short_description: Message {
+            text: "Production code unit changed".to_string(),
+            markdown: None,
+        },
+        default_configuration: RuleConfiguration { level: "note" },
+    }];
+    rules.extend(LIMIT_RULES.iter().map(|limit| Rule {
+        id: limit_rule_id(*limit),
+        name: limit.as_str().to_string(),
+        short_description: Message {
+            text: format!("Change set crosses the `{}` limit", limit.as_str()),
+            markdown: None,
+        },
+        default_configuration: RuleConfiguration { level: "error" },
+    }));
+    rules
+}
+
+fn change_result(change: &Change) -> SarifResult<'_> {
+    let qualified_name = change.unit.qualified_name();
+    let suppressions = change
+        .suppression
+        .iter()
+        .map(|suppression| SarifSuppression {
+            kind: match suppression {
+                Suppression::Allowlist { .. } => "external",
+                _ => "inSource",
+            },
+            justification: suppression.describe(),
+        })
+        .collect();
+
+    SarifResult {
+        rule_id: PRODUCTION_CHANGE_RULE.to_string(),
+        rule_index: 0,
+        level: "note",
+        message: Message {
+            text: format!(
+                "Production {} `{}` changed (+{} -{})",
+                change.unit.kind.as_str(),
+                qualified_name,
+                change.lines_added,
+                change.lines_removed
+            ),
+            markdown: None,
+        },
+        locations: vec![location(change)],
+        suppressions,
+        properties: Properties::Change {
+            kind: change.unit.kind.as_str(),
+            classification: change.classification.as_str(),
+            qualified_name,
+            lines_added: change.lines_added,
+            lines_removed: change.lines_removed,
+        },
+    }
+}
+
+fn violation_result<'a>(
+    violation: &'a LimitViolation,
+    result: &AnalysisResult,
+    config: &Config,
+) -> Option<SarifResult<'a>> {
+    let rule_index = LIMIT_RULES
+        .iter()
+        .position(|limit| *limit == violation.limit)
+        .map_or(0, |index| index + 1);
+    let markdown = violation.describe();
+
+    let location = result
+        .changes
+        .iter()
+        .find(|change| contributes_to(change, violation, config))
+        .map(location)
+        .or_else(|| {
+            result
+                .scope
+                .analyzed_files
+                .first()
+                .map(|path| file_location(path))
+        })?;
+
+    Some(SarifResult {
+        rule_id: limit_rule_id(violation.limit),
+        rule_index,
+        level: match violation.severity {
+            LimitStatus::Fail => "error",
+            LimitStatus::Warn | LimitStatus::Ok => "warning",
+        },
+        message: Message {
+            text: violation.describe_text(),
+            markdown: Some(markdown),
+        },
+        locations: vec![location],
+        suppressions: Vec::new(),
+        properties: Properties::Violation {
+            actual: violation.actual,
+            threshold: violation.threshold,
+            scope: &violation.scope,
+        },
+    })
+}
+
+fn location(change: &Change) -> Location {
+    physical_location(
+        &change.file_path,
+        change.unit.span.start,
+        change.unit.span.end,
+    )
+}
+
+/// Points at the first line of a file, for results not tied to a unit
+fn file_location(path: &Path) -> Location {
+    physical_location(path, 1, 1)
+}
+
+fn physical_location(path: &Path, start_line: usize, end_line: usize) -> Location {
+    Location {
+        physical_location: PhysicalLocation {
+            artifact_location: ArtifactLocation {
+                uri: path.to_string_lossy().replace('\\', "/"),
+            },
+            region: Region {
+                start_line,
+                end_line,
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::{
+        classifier::rules::evaluate_limits,
+        types::{
+            AnalysisScope, CodeType, LineSpan, SemanticUnit, SemanticUnitKind, Summary, Visibility,
+        },
+    };
+
+    fn change(name: &str, classification: CodeType) -> Change {
+        Change::new(
+            PathBuf::from("src/lib.rs"),
+            SemanticUnit::new(
+                SemanticUnitKind::Function,
+                name.to_string(),
+                Visibility::Public,
+                LineSpan::new(3, 9),
+                vec![],
+            ),
+            classification,
+            4,
+            1,
+        )
+    }
+
+    #[test]
+    fn test_sarif_results_for_changes_and_violations() {
+        let mut config = Config::default();
+        config.limits.max_prod_units = 0;
+
+        let changes = vec![
+            change("handler", CodeType::Production),
+            change("test_handler", CodeType::Test),
+        ];
+        let summary = Summary {
+            prod_functions: 1,
+            ..Default::default()
+        };
+        let violations = evaluate_limits(&changes, &summary, &config);
+        let result = AnalysisResult::new(changes, summary, AnalysisScope::new())
+            .with_violations(violations);
+
+        let output = SarifFormatter
+            .format(&result, &config)
+            .expect("format should succeed");
+        let log: serde_json::Value = serde_json::from_str(&output).expect("valid JSON");
+
+        assert_eq!(log["version"], "2.1.0");
+        let run = &log["runs"][0];
+        assert_eq!(
+            run["tool"]["driver"]["rules"][1]["id"],
+            "limit/max_prod_units"
+        );
+
+        let results = run["results"].as_array().expect("results array");
+        assert_eq!(results.len(), 2);
+
+        assert_eq!(results[0]["ruleId"], "production-change");
+        assert_eq!(results[0]["level"], "note");
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/lib.rs");
+        assert_eq!(location["region"]["startLine"], 3);
+        assert_eq!(location["region"]["endLine"], 9);
+        assert_eq!(results[0]["properties"]["kind"], "function");
+        assert_eq!(results[0]["properties"]["classification"], "production");
+
+        assert_eq!(results[1]["ruleId"], "limit/max_prod_units");
+        assert_eq!(results[1]["ruleIndex"], 1);
+        assert_eq!(results[1]["level"], "error");
+        assert_eq!(results[1]["message"]["text"], "1 units (limit: 0)");
+        assert_eq!(results[1]["properties"]["scope"], "total");
+    }
+
+    #[test]
+    fn test_violation_without_contributing_change_located_at_file() {
+        let mut config = Config::default();
+        config.limits.min_test_ratio = Some(0.5);
+
+        let summary = Summary {
+            prod_lines_added: 10,
+            ..Default::default()
+        };
+        let violations = evaluate_limits(&[], &summary, &config);
+        let mut scope = AnalysisScope::new();
+        scope.add_analyzed(PathBuf::from("src/lib.rs"));
+        let result = AnalysisResult::new(vec![], summary.clone(), scope)
+            .with_violations(violations.clone());
+
+        let output = SarifFormatter
+            .format(&result, &config)
+            .expect("format should succeed");
+        let log: serde_json::Value = serde_json::from_str(&output).expect("valid JSON");
+        let results = &log["runs"][0]["results"];
+        assert_eq!(results[0]["ruleId"], "limit/min_test_ratio");
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/lib.rs");
+        assert_eq!(location["region"]["startLine"], 1);
+
+        let unlocated =
+            AnalysisResult::new(vec![], summary, AnalysisScope::new()).with_violations(violations);
+        let output = SarifFormatter
+            .format(&unlocated, &config)
+            .expect("format should succeed");
+        let log: serde_json::Value = serde_json::from_str(&output).expect("valid JSON");
+        assert_eq!(log["runs"][0]["results"], serde_json::json!([]));
+    }
+}
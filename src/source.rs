@@ -0,0 +1,174 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+pub mod filesystem;
+pub mod git;
+pub mod memory;
+pub mod tar;
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+pub use filesystem::FsSource;
+pub use git::GitSource;
+pub use memory::MemorySource;
+pub use tar::TarSource;
+
+/// Number of leading bytes inspected for binary detection, as git does
+const BINARY_PROBE_LEN: usize = 8000;
+
+/// Side of a diff a file version belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Revision {
+    /// Content before the change (`a/` side of the diff)
+    Old,
+    /// Content after the change (`b/` side of the diff)
+    New,
+}
+
+impl Revision {
+    /// Returns revision as a lowercase string
+    ///
+    /// # Returns
+    ///
+    /// `old` or `new`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::source::Revision;
+    ///
+    /// assert_eq!(Revision::Old.as_str(), "old");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Old => "old",
+            Self::New => "new",
+        }
+    }
+}
+
+/// Supplies file contents for the two revisions a diff compares
+///
+/// Paths are repository-relative; the old revision of a renamed file is
+/// requested under its old path. The mapper asks for every content it needs
+/// in a single [`SourceProvider::read_batch`] call, so providers backed by
+/// an external process can serve all files at once.
+pub trait SourceProvider {
+    /// Reads one file at a revision
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Repository-relative path
+    /// * `revision` - Side of the diff to read
+    ///
+    /// # Returns
+    ///
+    /// Raw file content or error
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file does not exist at the revision or cannot
+    /// be read
+    fn read(&self, path: &Path, revision: Revision) -> io::Result<Vec<u8>>;
+
+    /// Reads several files at once
+    ///
+    /// The default implementation calls [`SourceProvider::read`] for each
+    /// request.
+    ///
+    /// # Arguments
+    ///
+    /// * `requests` - Paths with the revision to read them at
+    ///
+    /// # Returns
+    ///
+    /// One result per request, in request order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use rust_diff_analyzer::source::{MemorySource, Revision, SourceProvider};
+    ///
+    /// let source = MemorySource::new().with_file(Revision::New, "src/lib.rs", "fn a() {}");
+    /// let contents = source.read_batch(&[
+    ///     (PathBuf::from("src/lib.rs"), Revision::New),
+    ///     (PathBuf::from("src/lib.rs"), Revision::Old),
+    /// ]);
+    /// assert!(contents[0].is_ok());
+    /// assert!(contents[1].is_err());
+    /// ```
+    fn read_batch(&self, requests: &[(PathBuf, Revision)]) -> Vec<io::Result<Vec<u8>>> {
+        requests
+            .iter()
+            .map(|(path, revision)| self.read(path, *revision))
+            .collect()
+    }
+}
+
+/// Checks whether file content is binary
+///
+/// Like git, content is considered binary when its first 8000 bytes
+/// contain a NUL byte.
+///
+/// # Arguments
+///
+/// * `content` - Raw file content
+///
+/// # Returns
+///
+/// `true` if the content is binary
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::source::is_binary;
+///
+/// assert!(is_binary(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"));
+/// assert!(!is_binary(b"fn main() {}\n"));
+/// ```
+pub fn is_binary(content: &[u8]) -> bool {
+    content.iter().take(BINARY_PROBE_LEN).any(|byte| *byte == 0)
+}
+
+/// Decodes file content as UTF-8 text
+///
+/// # Arguments
+///
+/// * `content` - Raw file content
+///
+/// # Returns
+///
+/// Text content or error
+///
+/// # Errors
+///
+/// Returns `InvalidData` error if the content is not valid UTF-8
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::source::decode_text;
+///
+/// assert_eq!(decode_text(b"fn a() {}".to_vec()).unwrap(), "fn a() {}");
+/// assert!(decode_text(vec![0xff, 0xfe]).is_err());
+/// ```
+pub fn decode_text(content: Vec<u8>) -> io::Result<String> {
+    String::from_utf8(content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Builds the error returned for a file missing from a revision
+fn not_found(path: &Path, revision: Revision) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!(
+            "'{}' not found in {} revision",
+            path.display(),
+            revision.as_str()
+        ),
+    )
+}
@@ -0,0 +1,60 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::{Revision, SourceProvider};
+
+/// Source provider reading the new revision from a checkout
+///
+/// A working tree only holds the post-change files, so requests for the old
+/// revision fail with [`io::ErrorKind::Unsupported`]. Combine it with
+/// [`GitSource`](super::GitSource) to also read old contents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FsSource {
+    base_dir: PathBuf,
+}
+
+impl FsSource {
+    /// Creates a filesystem source rooted at a directory
+    ///
+    /// # Arguments
+    ///
+    /// * `base_dir` - Repository root that diff paths are relative to
+    ///
+    /// # Returns
+    ///
+    /// A new FsSource instance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::source::{FsSource, Revision, SourceProvider};
+    ///
+    /// let source = FsSource::new(".");
+    /// assert!(source.read(Path::new("Cargo.toml"), Revision::New).is_ok());
+    /// assert!(source.read(Path::new("Cargo.toml"), Revision::Old).is_err());
+    /// ```
+    pub fn new(base_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_dir: base_dir.into(),
+        }
+    }
+}
+
+impl SourceProvider for FsSource {
+    fn read(&self, path: &Path, revision: Revision) -> io::Result<Vec<u8>> {
+        match revision {
+            Revision::New => fs::read(self.base_dir.join(path)),
+            Revision::Old => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "the working tree only holds the new revision",
+            )),
+        }
+    }
+}
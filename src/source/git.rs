@@ -0,0 +1,305 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{
+    fs,
+    io::{self, BufRead, BufReader, Read, Write},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+};
+
+use super::{Revision, SourceProvider};
+
+/// Source provider reading file contents from git revisions
+///
+/// The old revision is read from a commit-ish such as `origin/main`. The
+/// new revision is read from another commit-ish when one is set, otherwise
+/// from the working tree. Object reads of a batch share one
+/// `git cat-file --batch` process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitSource {
+    repo_dir: PathBuf,
+    old_rev: String,
+    new_rev: Option<String>,
+}
+
+impl GitSource {
+    /// Creates a git source comparing a revision with the working tree
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_dir` - Repository root that diff paths are relative to
+    /// * `old_rev` - Revision holding the old contents
+    ///
+    /// # Returns
+    ///
+    /// A new GitSource instance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::source::GitSource;
+    ///
+    /// let source = GitSource::new(".", "origin/main");
+    /// assert_eq!(source.old_rev(), "origin/main");
+    /// assert_eq!(source.new_rev(), None);
+    /// ```
+    pub fn new(repo_dir: impl Into<PathBuf>, old_rev: impl Into<String>) -> Self {
+        Self {
+            repo_dir: repo_dir.into(),
+            old_rev: old_rev.into(),
+            new_rev: None,
+        }
+    }
+
+    /// Reads the new contents from a revision instead of the working tree
+    ///
+    /// # Arguments
+    ///
+    /// * `new_rev` - Revision holding the new contents
+    ///
+    /// # Returns
+    ///
+    /// Source reading both sides from git objects
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::source::GitSource;
+    ///
+    /// let source = GitSource::new(".", "HEAD~1").with_new_rev("HEAD");
+    /// assert_eq!(source.new_rev(), Some("HEAD"));
+    /// ```
+    pub fn with_new_rev(mut self, new_rev: impl Into<String>) -> Self {
+        self.new_rev = Some(new_rev.into());
+        self
+    }
+
+    /// Returns the revision holding the old contents
+    ///
+    /// # Returns
+    ///
+    /// Old revision as given
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::source::GitSource;
+    ///
+    /// assert_eq!(GitSource::new(".", "v1.0").old_rev(), "v1.0");
+    /// ```
+    pub fn old_rev(&self) -> &str {
+        &self.old_rev
+    }
+
+    /// Returns the revision holding the new contents
+    ///
+    /// # Returns
+    ///
+    /// New revision, or `None` when the working tree is read
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::source::GitSource;
+    ///
+    /// assert!(GitSource::new(".", "v1.0").new_rev().is_none());
+    /// ```
+    pub fn new_rev(&self) -> Option<&str> {
+        self.new_rev.as_deref()
+    }
+
+    fn rev(&self, revision: Revision) -> Option<&str> {
+        match revision {
+            Revision::Old => Some(&self.old_rev),
+            Revision::New => self.new_rev.as_deref(),
+        }
+    }
+
+    /// Reads `<rev>:<path>` objects through one `git cat-file --batch`
+    fn cat_files(&self, specs: Vec<String>) -> Vec<io::Result<Vec<u8>>> {
+        match self.run_cat_file(&specs) {
+            Ok(contents) => contents,
+            Err(e) => specs
+                .iter()
+                .map(|_| Err(io::Error::new(e.kind(), e.to_string())))
+                .collect(),
+        }
+    }
+
+    fn run_cat_file(&self, specs: &[String]) -> io::Result<Vec<io::Result<Vec<u8>>>> {
+        let mut child = Command::new("git")
+            .arg("cat-file")
+            .arg("--batch")
+            .current_dir(&self.repo_dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let input: String = specs.iter().map(|spec| format!("{}\n", spec)).collect();
+        let mut stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| io::Error::other("no stdin"))?;
+        let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+
+        // Drained concurrently so a chatty git cannot block on a full pipe
+        let mut stderr_pipe = child
+            .stderr
+            .take()
+            .ok_or_else(|| io::Error::other("no stderr"))?;
+        let stderr = thread::spawn(move || {
+            let mut stderr = String::new();
+            let _ = stderr_pipe.read_to_string(&mut stderr);
+            stderr
+        });
+
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| io::Error::other("no stdout"))?;
+        let mut reader = BufReader::new(stdout);
+        let mut contents = Vec::with_capacity(specs.len());
+        for spec in specs {
+            match read_object(&mut reader, spec)? {
+                Some(content) => contents.push(content),
+                None => {
+                    let _ = child.wait();
+                    let stderr = stderr.join().unwrap_or_default();
+                    return Err(io::Error::other(format!(
+                        "git cat-file exited early: {}",
+                        stderr.trim()
+                    )));
+                }
+            }
+        }
+
+        writer
+            .join()
+            .map_err(|_| io::Error::other("git cat-file writer panicked"))??;
+        child.wait()?;
+        let _ = stderr.join();
+        Ok(contents)
+    }
+}
+
+impl SourceProvider for GitSource {
+    fn read(&self, path: &Path, revision: Revision) -> io::Result<Vec<u8>> {
+        let request = [(path.to_path_buf(), revision)];
+        self.read_batch(&request)
+            .pop()
+            .unwrap_or_else(|| Err(io::Error::other("no content returned")))
+    }
+
+    fn read_batch(&self, requests: &[(PathBuf, Revision)]) -> Vec<io::Result<Vec<u8>>> {
+        let specs: Vec<String> = requests
+            .iter()
+            .filter_map(|(path, revision)| {
+                self.rev(*revision)
+                    .map(|rev| format!("{}:{}", rev, object_path(path)))
+            })
+            .collect();
+        let mut objects = if specs.is_empty() {
+            Vec::new()
+        } else {
+            self.cat_files(specs)
+        }
+        .into_iter();
+
+        requests
+            .iter()
+            .map(|(path, revision)| match self.rev(*revision) {
+                Some(_) => objects
+                    .next()
+                    .unwrap_or_else(|| Err(io::Error::other("no content returned"))),
+                None => fs::read(self.repo_dir.join(path)),
+            })
+            .collect()
+    }
+}
+
+/// Formats a path the way git object names expect it
+fn object_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Reads one `git cat-file --batch` response
+///
+/// Returns `None` when the output ends before the response, which means
+/// git failed (for example outside a repository).
+fn read_object<R: BufRead>(reader: &mut R, spec: &str) -> io::Result<Option<io::Result<Vec<u8>>>> {
+    let mut header = String::new();
+    if reader.read_line(&mut header)? == 0 {
+        return Ok(None);
+    }
+
+    // A failed lookup echoes the object name, which may contain spaces,
+    // followed by the status: `<spec> missing`
+    let line = header.trim_end_matches('\n');
+    if let Some((_, status)) = line.rsplit_once(' ').filter(|(name, _)| *name == spec) {
+        return Ok(Some(Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("'{}' is {} in git", spec, status),
+        ))));
+    }
+
+    let fields: Vec<&str> = line.split(' ').collect();
+    let (object_type, size) = match fields.as_slice() {
+        [_, object_type, size] => match size.parse::<usize>() {
+            Ok(size) => (*object_type, size),
+            Err(_) => return Err(malformed(&header)),
+        },
+        _ => return Err(malformed(&header)),
+    };
+
+    let mut content = vec![0; size];
+    reader.read_exact(&mut content)?;
+    let mut newline = [0; 1];
+    reader.read_exact(&mut newline)?;
+
+    if object_type == "blob" {
+        Ok(Some(Ok(content)))
+    } else {
+        Ok(Some(Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("'{}' is a {}, not a file", spec, object_type),
+        ))))
+    }
+}
+
+fn malformed(header: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("unexpected git cat-file output: {}", header.trim()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_object_responses() {
+        let output = b"1234 blob 5\nfn a\n\nHEAD:gone.rs missing\nHEAD:my file.rs missing\n5678 \
+                       tree 0\n\n";
+        let mut reader = &output[..];
+
+        let blob = read_object(&mut reader, "HEAD:a.rs").unwrap().unwrap();
+        assert_eq!(blob.unwrap(), b"fn a\n");
+
+        let missing = read_object(&mut reader, "HEAD:gone.rs").unwrap().unwrap();
+        assert_eq!(missing.unwrap_err().kind(), io::ErrorKind::NotFound);
+
+        let spaced = read_object(&mut reader, "HEAD:my file.rs")
+            .unwrap()
+            .unwrap();
+        assert_eq!(spaced.unwrap_err().kind(), io::ErrorKind::NotFound);
+
+        let tree = read_object(&mut reader, "HEAD:src").unwrap().unwrap();
+        assert_eq!(tree.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        assert!(read_object(&mut reader, "HEAD:b.rs").unwrap().is_none());
+    }
+}
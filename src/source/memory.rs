@@ -0,0 +1,135 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{
+    collections::HashMap,
+    io,
+    path::{Path, PathBuf},
+};
+
+use super::{Revision, SourceProvider, not_found};
+
+/// Source provider serving file contents held in memory
+///
+/// Intended for tests and for embedding the analyzer where contents are
+/// already loaded, e.g. from a code review service.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemorySource {
+    old: HashMap<PathBuf, Vec<u8>>,
+    new: HashMap<PathBuf, Vec<u8>>,
+}
+
+impl MemorySource {
+    /// Creates an empty in-memory source
+    ///
+    /// # Returns
+    ///
+    /// A new MemorySource without files
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::source::MemorySource;
+    ///
+    /// let source = MemorySource::new();
+    /// assert!(source.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a file, consuming and returning the source
+    ///
+    /// # Arguments
+    ///
+    /// * `revision` - Side of the diff the content belongs to
+    /// * `path` - Repository-relative path
+    /// * `content` - File content
+    ///
+    /// # Returns
+    ///
+    /// Source with the file added
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::source::{MemorySource, Revision, SourceProvider};
+    ///
+    /// let source = MemorySource::new()
+    ///     .with_file(Revision::Old, "src/lib.rs", "fn a() {}")
+    ///     .with_file(Revision::New, "src/lib.rs", "fn b() {}");
+    /// let old = source.read(Path::new("src/lib.rs"), Revision::Old).unwrap();
+    /// assert_eq!(old, b"fn a() {}");
+    /// ```
+    pub fn with_file(
+        mut self,
+        revision: Revision,
+        path: impl Into<PathBuf>,
+        content: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.insert(revision, path, content);
+        self
+    }
+
+    /// Adds or replaces a file
+    ///
+    /// # Arguments
+    ///
+    /// * `revision` - Side of the diff the content belongs to
+    /// * `path` - Repository-relative path
+    /// * `content` - File content
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::source::{MemorySource, Revision};
+    ///
+    /// let mut source = MemorySource::new();
+    /// source.insert(Revision::New, "src/lib.rs", "fn a() {}");
+    /// assert!(!source.is_empty());
+    /// ```
+    pub fn insert(
+        &mut self,
+        revision: Revision,
+        path: impl Into<PathBuf>,
+        content: impl Into<Vec<u8>>,
+    ) {
+        let files = match revision {
+            Revision::Old => &mut self.old,
+            Revision::New => &mut self.new,
+        };
+        files.insert(path.into(), content.into());
+    }
+
+    /// Checks whether the source holds no files
+    ///
+    /// # Returns
+    ///
+    /// `true` if neither revision has files
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::source::MemorySource;
+    ///
+    /// assert!(MemorySource::default().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.old.is_empty() && self.new.is_empty()
+    }
+}
+
+impl SourceProvider for MemorySource {
+    fn read(&self, path: &Path, revision: Revision) -> io::Result<Vec<u8>> {
+        let files = match revision {
+            Revision::Old => &self.old,
+            Revision::New => &self.new,
+        };
+        files
+            .get(path)
+            .cloned()
+            .ok_or_else(|| not_found(path, revision))
+    }
+}
@@ -0,0 +1,280 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use masterror::AppError;
+
+use super::{MemorySource, Revision, SourceProvider};
+use crate::error::ArchiveError;
+
+/// Size of a tar header and of the blocks entry data is padded to
+const BLOCK_SIZE: usize = 512;
+
+/// Source provider serving file contents from tar archives
+///
+/// Each revision is loaded from an uncompressed tar archive, such as one
+/// produced by `git archive --format=tar`. Regular files are kept in
+/// memory; directories, links and other entries are ignored. Leading `./`
+/// components are stripped from entry names.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TarSource {
+    files: MemorySource,
+}
+
+impl TarSource {
+    /// Creates a source from the archive of the new revision
+    ///
+    /// # Arguments
+    ///
+    /// * `archive` - Uncompressed tar archive bytes
+    ///
+    /// # Returns
+    ///
+    /// Source serving the archived files as the new revision, or error
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the archive is malformed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::source::TarSource;
+    ///
+    /// // An archive consisting of the two end-of-archive blocks
+    /// let source = TarSource::from_archive(&[0; 1024]).unwrap();
+    /// assert_eq!(source, TarSource::default());
+    /// ```
+    pub fn from_archive(archive: &[u8]) -> Result<Self, AppError> {
+        Self::default().with_archive(Revision::New, archive)
+    }
+
+    /// Adds the archived files of a revision
+    ///
+    /// # Arguments
+    ///
+    /// * `revision` - Side of the diff the archive holds
+    /// * `archive` - Uncompressed tar archive bytes
+    ///
+    /// # Returns
+    ///
+    /// Source with the archived files added, or error
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the archive is malformed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::source::{Revision, TarSource};
+    ///
+    /// let source = TarSource::from_archive(&[0; 1024])
+    ///     .and_then(|source| source.with_archive(Revision::Old, &[0; 1024]))
+    ///     .unwrap();
+    /// assert_eq!(source, TarSource::default());
+    /// ```
+    pub fn with_archive(mut self, revision: Revision, archive: &[u8]) -> Result<Self, AppError> {
+        for (path, content) in read_archive(archive)? {
+            self.files.insert(revision, path, content);
+        }
+        Ok(self)
+    }
+}
+
+impl SourceProvider for TarSource {
+    fn read(&self, path: &Path, revision: Revision) -> io::Result<Vec<u8>> {
+        self.files.read(path, revision)
+    }
+}
+
+/// Extracts the regular files of an uncompressed tar archive
+///
+/// Supports ustar names with prefixes, GNU long names and pax `path`
+/// records.
+///
+/// # Arguments
+///
+/// * `archive` - Tar archive bytes
+///
+/// # Returns
+///
+/// Paths and contents of regular files in archive order, or error
+///
+/// # Errors
+///
+/// Returns error if a header or entry is truncated or has an invalid size
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::source::tar::read_archive;
+///
+/// assert!(read_archive(&[]).unwrap().is_empty());
+/// assert!(read_archive(&[1; 100]).is_err());
+/// ```
+pub fn read_archive(archive: &[u8]) -> Result<Vec<(PathBuf, Vec<u8>)>, AppError> {
+    let mut files = Vec::new();
+    let mut long_name: Option<String> = None;
+    let mut offset = 0;
+
+    while offset < archive.len() {
+        let header = archive
+            .get(offset..offset + BLOCK_SIZE)
+            .ok_or_else(|| archive_error(format!("truncated header at byte {}", offset)))?;
+        if header.iter().all(|byte| *byte == 0) {
+            break;
+        }
+
+        let size = parse_octal(&header[124..136])
+            .ok_or_else(|| archive_error(format!("invalid entry size at byte {}", offset)))?;
+        let start = offset + BLOCK_SIZE;
+        let data = archive
+            .get(start..start + size)
+            .ok_or_else(|| archive_error(format!("truncated entry at byte {}", offset)))?;
+
+        match header[156] {
+            b'L' => long_name = Some(c_string(data)),
+            b'x' => long_name = pax_path(data).or(long_name),
+            b'0' | 0 => {
+                let name = long_name.take().unwrap_or_else(|| header_name(header));
+                files.push((normalize(&name), data.to_vec()));
+            }
+            _ => long_name = None,
+        }
+
+        offset = start + size.div_ceil(BLOCK_SIZE) * BLOCK_SIZE;
+    }
+
+    Ok(files)
+}
+
+/// Returns the entry name stored in a header, joined with its ustar prefix
+fn header_name(header: &[u8]) -> String {
+    let name = c_string(&header[0..100]);
+    if &header[257..263] != b"ustar\0" {
+        return name;
+    }
+    let prefix = c_string(&header[345..500]);
+    if prefix.is_empty() {
+        name
+    } else {
+        format!("{}/{}", prefix, name)
+    }
+}
+
+/// Finds the `path` record of a pax extended header
+fn pax_path(data: &[u8]) -> Option<String> {
+    let mut rest = data;
+    while !rest.is_empty() {
+        let space = rest.iter().position(|byte| *byte == b' ')?;
+        let len: usize = std::str::from_utf8(&rest[..space]).ok()?.parse().ok()?;
+        let record = rest.get(space + 1..len)?;
+        let record = record.strip_suffix(b"\n").unwrap_or(record);
+        if let Some(path) = record.strip_prefix(b"path=") {
+            return Some(String::from_utf8_lossy(path).into_owned());
+        }
+        rest = &rest[len..];
+    }
+    None
+}
+
+fn parse_octal(field: &[u8]) -> Option<usize> {
+    let text = std::str::from_utf8(field).ok()?;
+    let text = text.trim_matches(|c: char| c == '\0' || c == ' ');
+    if text.is_empty() {
+        return Some(0);
+    }
+    usize::from_str_radix(text, 8).ok()
+}
+
+fn c_string(bytes: &[u8]) -> String {
+    let end = bytes
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+fn normalize(name: &str) -> PathBuf {
+    let mut name = name;
+    while let Some(rest) = name.strip_prefix("./") {
+        name = rest;
+    }
+    PathBuf::from(name)
+}
+
+fn archive_error(message: String) -> AppError {
+    AppError::from(ArchiveError { message })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(name: &str, size: usize, typeflag: u8) -> Vec<u8> {
+        let mut header = vec![0; BLOCK_SIZE];
+        header[..name.len()].copy_from_slice(name.as_bytes());
+        let size = format!("{:011o}\0", size);
+        header[124..136].copy_from_slice(size.as_bytes());
+        header[156] = typeflag;
+        header[257..263].copy_from_slice(b"ustar\0");
+        header
+    }
+
+    fn entry(name: &str, typeflag: u8, data: &[u8]) -> Vec<u8> {
+        let mut bytes = header(name, data.len(), typeflag);
+        bytes.extend_from_slice(data);
+        bytes.resize(bytes.len().div_ceil(BLOCK_SIZE) * BLOCK_SIZE, 0);
+        bytes
+    }
+
+    #[test]
+    fn test_read_archive_entries() {
+        let long = format!("src/{}.rs", "nested/".repeat(20));
+        let pax = format!("{} path={}\n", long.len() + 10, long);
+        assert_eq!(pax.len(), long.len() + 10);
+
+        let mut archive = Vec::new();
+        archive.extend(entry("./src/", b'5', b""));
+        archive.extend(entry("./src/lib.rs", b'0', b"fn a() {}\n"));
+        archive.extend(entry("pax_global_header", b'g', b"20 comment=abcdef\n"));
+        archive.extend(entry("PaxHeaders/long", b'x', pax.as_bytes()));
+        archive.extend(entry("src/truncated", b'0', b"fn long() {}\n"));
+        archive.extend(vec![0; 2 * BLOCK_SIZE]);
+
+        let files = read_archive(&archive).expect("archive should parse");
+        assert_eq!(
+            files,
+            vec![
+                (PathBuf::from("src/lib.rs"), b"fn a() {}\n".to_vec()),
+                (PathBuf::from(long), b"fn long() {}\n".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tar_source_serves_both_revisions() {
+        let old = entry("src/lib.rs", b'0', b"fn old() {}\n");
+        let new = entry("src/lib.rs", b'0', b"fn new() {}\n");
+        let source = TarSource::from_archive(&new)
+            .and_then(|source| source.with_archive(Revision::Old, &old))
+            .expect("archives should parse");
+
+        let path = Path::new("src/lib.rs");
+        assert_eq!(source.read(path, Revision::Old).unwrap(), b"fn old() {}\n");
+        assert_eq!(source.read(path, Revision::New).unwrap(), b"fn new() {}\n");
+        assert!(
+            source
+                .read(Path::new("src/gone.rs"), Revision::New)
+                .is_err()
+        );
+
+        let truncated = &new[..BLOCK_SIZE + 4];
+        assert!(TarSource::from_archive(truncated).is_err());
+    }
+}
@@ -3,10 +3,14 @@
 
 pub mod change;
 pub mod classification;
+pub mod limit;
 pub mod scope;
 pub mod semantic_unit;
+pub mod suppression;
 
-pub use change::{AnalysisResult, Change, Summary};
-pub use classification::CodeType;
+pub use change::{AnalysisResult, Change, FeatureStats, Summary, TypeStats};
+pub use classification::{ClassificationReason, CodeType};
+pub use limit::{LimitCheck, LimitKind, LimitScope, LimitStatus, LimitValue, LimitViolation};
 pub use scope::{AnalysisScope, ExclusionReason, SkippedFile};
 pub use semantic_unit::{LineSpan, SemanticUnit, SemanticUnitKind, Visibility};
+pub use suppression::Suppression;
@@ -1,11 +1,17 @@
 // SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
-use std::path::PathBuf;
+use std::{collections::BTreeMap, path::PathBuf};
 
 use serde::{Deserialize, Serialize};
 
-use super::{classification::CodeType, scope::AnalysisScope, semantic_unit::SemanticUnit};
+use super::{
+    classification::{ClassificationReason, CodeType},
+    limit::{LimitCheck, LimitKind, LimitScope, LimitStatus, LimitViolation, overall_status},
+    scope::AnalysisScope,
+    semantic_unit::SemanticUnit,
+    suppression::Suppression,
+};
 
 /// A change to a semantic unit
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -20,6 +26,12 @@ pub struct Change {
     pub lines_added: usize,
     /// Number of lines removed
     pub lines_removed: usize,
+    /// What decided the classification, when known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<ClassificationReason>,
+    /// Why the change is excluded from counts and limits, if it is
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suppression: Option<Suppression>,
 }
 
 impl Change {
@@ -77,9 +89,135 @@ impl Change {
             classification,
             lines_added,
             lines_removed,
+            reason: None,
+            suppression: None,
         }
     }
 
+    /// Records what decided the classification
+    ///
+    /// # Arguments
+    ///
+    /// * `reason` - Classification provenance
+    ///
+    /// # Returns
+    ///
+    /// The change with the reason set
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use rust_diff_analyzer::types::{
+    ///     Change, ClassificationReason, CodeType, LineSpan, SemanticUnit, SemanticUnitKind,
+    ///     Visibility,
+    /// };
+    ///
+    /// let unit = SemanticUnit::new(
+    ///     SemanticUnitKind::Function,
+    ///     "parse".to_string(),
+    ///     Visibility::Public,
+    ///     LineSpan::new(10, 30),
+    ///     vec![],
+    /// );
+    ///
+    /// let change = Change::new(
+    ///     PathBuf::from("src/parser.rs"),
+    ///     unit,
+    ///     CodeType::Production,
+    ///     10,
+    ///     5,
+    /// )
+    /// .with_reason(ClassificationReason::Default);
+    ///
+    /// assert_eq!(change.reason, Some(ClassificationReason::Default));
+    /// ```
+    pub fn with_reason(mut self, reason: ClassificationReason) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
+    /// Marks the change as suppressed
+    ///
+    /// # Arguments
+    ///
+    /// * `suppression` - Marker that opted the unit out
+    ///
+    /// # Returns
+    ///
+    /// The change with the suppression set
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use rust_diff_analyzer::types::{
+    ///     Change, CodeType, LineSpan, SemanticUnit, SemanticUnitKind, Suppression, Visibility,
+    /// };
+    ///
+    /// let unit = SemanticUnit::new(
+    ///     SemanticUnitKind::Function,
+    ///     "opcodes".to_string(),
+    ///     Visibility::Public,
+    ///     LineSpan::new(1, 900),
+    ///     vec![],
+    /// );
+    ///
+    /// let change = Change::new(
+    ///     PathBuf::from("src/vm.rs"),
+    ///     unit,
+    ///     CodeType::Production,
+    ///     850,
+    ///     0,
+    /// )
+    /// .with_suppression(Suppression::Comment);
+    ///
+    /// assert!(change.is_suppressed());
+    /// ```
+    pub fn with_suppression(mut self, suppression: Suppression) -> Self {
+        self.suppression = Some(suppression);
+        self
+    }
+
+    /// Checks whether the change is excluded from counts and limits
+    ///
+    /// # Returns
+    ///
+    /// `true` if a suppression marker applies
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use rust_diff_analyzer::types::{
+    ///     Change, CodeType, LineSpan, SemanticUnit, SemanticUnitKind, Visibility,
+    /// };
+    ///
+    /// let unit = SemanticUnit::new(
+    ///     SemanticUnitKind::Function,
+    ///     "parse".to_string(),
+    ///     Visibility::Public,
+    ///     LineSpan::new(10, 30),
+    ///     vec![],
+    /// );
+    ///
+    /// let change = Change::new(
+    ///     PathBuf::from("src/parser.rs"),
+    ///     unit,
+    ///     CodeType::Production,
+    ///     10,
+    ///     5,
+    /// );
+    ///
+    /// assert!(!change.is_suppressed());
+    /// ```
+    pub fn is_suppressed(&self) -> bool {
+        self.suppression.is_some()
+    }
+
     /// Returns total lines changed (added + removed)
     ///
     /// # Returns
@@ -118,6 +256,114 @@ impl Change {
     }
 }
 
+/// Production change counters for a single cargo feature
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureStats {
+    /// Number of production units gated by the feature
+    pub units: usize,
+    /// Lines added in units gated by the feature
+    pub lines_added: usize,
+    /// Lines removed from units gated by the feature
+    pub lines_removed: usize,
+}
+
+impl FeatureStats {
+    /// Records a change in the counters
+    ///
+    /// # Arguments
+    ///
+    /// * `change` - Change to record
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use rust_diff_analyzer::types::{
+    ///     Change, CodeType, FeatureStats, LineSpan, SemanticUnit, SemanticUnitKind, Visibility,
+    /// };
+    ///
+    /// let unit = SemanticUnit::new(
+    ///     SemanticUnitKind::Function,
+    ///     "encode".to_string(),
+    ///     Visibility::Public,
+    ///     LineSpan::new(1, 5),
+    ///     vec!["cfg_feature:serde".to_string()],
+    /// );
+    /// let change = Change::new(
+    ///     PathBuf::from("src/lib.rs"),
+    ///     unit,
+    ///     CodeType::Production,
+    ///     4,
+    ///     1,
+    /// );
+    ///
+    /// let mut stats = FeatureStats::default();
+    /// stats.record(&change);
+    /// assert_eq!(stats.units, 1);
+    /// assert_eq!(stats.lines_added, 4);
+    /// ```
+    pub fn record(&mut self, change: &Change) {
+        self.units += 1;
+        self.lines_added += change.lines_added;
+        self.lines_removed += change.lines_removed;
+    }
+}
+
+/// Change counters for a single code type
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypeStats {
+    /// Number of units changed
+    pub units: usize,
+    /// Lines added
+    pub lines_added: usize,
+    /// Lines removed
+    pub lines_removed: usize,
+}
+
+impl TypeStats {
+    /// Records a change in the counters
+    ///
+    /// # Arguments
+    ///
+    /// * `change` - Change to record
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use rust_diff_analyzer::types::{
+    ///     Change, CodeType, LineSpan, SemanticUnit, SemanticUnitKind, TypeStats, Visibility,
+    /// };
+    ///
+    /// let unit = SemanticUnit::new(
+    ///     SemanticUnitKind::Function,
+    ///     "bench_parse".to_string(),
+    ///     Visibility::Private,
+    ///     LineSpan::new(1, 5),
+    ///     vec!["bench".to_string()],
+    /// );
+    /// let change = Change::new(
+    ///     PathBuf::from("benches/parse.rs"),
+    ///     unit,
+    ///     CodeType::Benchmark,
+    ///     5,
+    ///     0,
+    /// );
+    ///
+    /// let mut stats = TypeStats::default();
+    /// stats.record(&change);
+    /// assert_eq!(stats.units, 1);
+    /// assert_eq!(stats.lines_added, 5);
+    /// ```
+    pub fn record(&mut self, change: &Change) {
+        self.units += 1;
+        self.lines_added += change.lines_added;
+        self.lines_removed += change.lines_removed;
+    }
+}
+
 /// Summary of analysis results
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Summary {
@@ -137,10 +383,33 @@ pub struct Summary {
     pub test_lines_added: usize,
     /// Lines removed from test code
     pub test_lines_removed: usize,
+    /// Number of changed units excluded by suppression markers
+    #[serde(default)]
+    pub suppressed_units: usize,
+    /// Lines added in suppressed units
+    #[serde(default)]
+    pub suppressed_lines_added: usize,
+    /// Lines removed from suppressed units
+    #[serde(default)]
+    pub suppressed_lines_removed: usize,
     /// Weighted score based on configuration
     pub weighted_score: usize,
     /// Whether any limit was exceeded
     pub exceeds_limit: bool,
+    /// Worst status over all limits and warning thresholds
+    #[serde(default)]
+    pub status: LimitStatus,
+    /// Production changes grouped by the cargo feature gating them
+    ///
+    /// Units gated by several features are counted under each of them;
+    /// ungated units do not appear.
+    #[serde(default)]
+    pub features: BTreeMap<String, FeatureStats>,
+    /// Counters for each code type with changes
+    ///
+    /// Suppressed changes are not included.
+    #[serde(default)]
+    pub by_type: BTreeMap<CodeType, TypeStats>,
 }
 
 impl Summary {
@@ -166,6 +435,7 @@ impl Summary {
     ///     test_lines_removed: 30,
     ///     weighted_score: 0,
     ///     exceeds_limit: false,
+    ///     ..Default::default()
     /// };
     ///
     /// assert_eq!(summary.total_prod_units(), 8);
@@ -173,6 +443,73 @@ impl Summary {
     pub fn total_prod_units(&self) -> usize {
         self.prod_functions + self.prod_structs + self.prod_other
     }
+
+    /// Returns the ratio of test lines added to production lines added
+    ///
+    /// Only tests and test utilities count; benchmarks, examples and build
+    /// scripts do not.
+    ///
+    /// # Returns
+    ///
+    /// Ratio, or `None` when no production lines were added
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::{CodeType, Summary, TypeStats};
+    ///
+    /// let mut summary = Summary {
+    ///     prod_lines_added: 200,
+    ///     ..Default::default()
+    /// };
+    /// summary.by_type.insert(
+    ///     CodeType::Test,
+    ///     TypeStats {
+    ///         units: 1,
+    ///         lines_added: 50,
+    ///         lines_removed: 0,
+    ///     },
+    /// );
+    /// assert_eq!(summary.test_ratio(), Some(0.25));
+    /// assert_eq!(Summary::default().test_ratio(), None);
+    /// ```
+    pub fn test_ratio(&self) -> Option<f64> {
+        let test_lines = self.type_stats(CodeType::Test).lines_added
+            + self.type_stats(CodeType::TestUtility).lines_added;
+        (self.prod_lines_added > 0).then(|| test_lines as f64 / self.prod_lines_added as f64)
+    }
+
+    /// Returns the counters for a code type
+    ///
+    /// # Arguments
+    ///
+    /// * `code_type` - Code type
+    ///
+    /// # Returns
+    ///
+    /// Counters, all zero when the type has no changes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::{CodeType, Summary, TypeStats};
+    ///
+    /// let mut summary = Summary::default();
+    /// summary.by_type.insert(
+    ///     CodeType::Example,
+    ///     TypeStats {
+    ///         units: 2,
+    ///         lines_added: 30,
+    ///         lines_removed: 0,
+    ///     },
+    /// );
+    ///
+    /// assert_eq!(summary.type_stats(CodeType::Example).units, 2);
+    /// assert_eq!(summary.type_stats(CodeType::Fuzz), TypeStats::default());
+    /// ```
+    pub fn type_stats(&self, code_type: CodeType) -> TypeStats {
+        self.by_type.get(&code_type).cloned().unwrap_or_default()
+    }
 }
 
 /// Complete analysis result
@@ -184,6 +521,14 @@ pub struct AnalysisResult {
     pub summary: Summary,
     /// Analysis scope information
     pub scope: AnalysisScope,
+    /// Limits and warning thresholds crossed by the changes
+    #[serde(default)]
+    pub violations: Vec<LimitViolation>,
+    /// Every configured limit measured against the changes
+    ///
+    /// Empty when the result was built from violations alone.
+    #[serde(default)]
+    pub checks: Vec<LimitCheck>,
 }
 
 impl AnalysisResult {
@@ -212,11 +557,118 @@ impl AnalysisResult {
             changes,
             summary,
             scope,
+            violations: Vec::new(),
+            checks: Vec::new(),
         }
     }
 
+    /// Attaches evaluated limit violations
+    ///
+    /// The summary's `status` and `exceeds_limit` are derived from the
+    /// violations so both always agree with them.
+    ///
+    /// # Arguments
+    ///
+    /// * `violations` - Violations from
+    ///   [`evaluate_limits`](crate::classifier::rules::evaluate_limits)
+    ///
+    /// # Returns
+    ///
+    /// Result carrying the violations
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::{
+    ///     AnalysisResult, AnalysisScope, LimitKind, LimitScope, LimitStatus, LimitViolation, Summary,
+    /// };
+    ///
+    /// let result = AnalysisResult::new(vec![], Summary::default(), AnalysisScope::new())
+    ///     .with_violations(vec![LimitViolation::new(
+    ///         LimitKind::MaxWeightedScore,
+    ///         120.0,
+    ///         100.0,
+    ///         LimitScope::Total,
+    ///         LimitStatus::Fail,
+    ///     )]);
+    /// assert!(result.summary.exceeds_limit);
+    /// assert_eq!(result.summary.status, LimitStatus::Fail);
+    /// ```
+    pub fn with_violations(mut self, violations: Vec<LimitViolation>) -> Self {
+        self.summary.status = overall_status(&violations);
+        self.summary.exceeds_limit = self.summary.status == LimitStatus::Fail;
+        self.violations = violations;
+        self
+    }
+
+    /// Attaches evaluated limit checks
+    ///
+    /// The violations are derived from the checks, and the summary's
+    /// `status` and `exceeds_limit` from the violations, so every output
+    /// reports the same outcome.
+    ///
+    /// # Arguments
+    ///
+    /// * `checks` - Checks from [`check_limits`](crate::classifier::rules::check_limits)
+    ///
+    /// # Returns
+    ///
+    /// Result carrying the checks and their violations
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::{
+    ///     AnalysisResult, AnalysisScope, LimitCheck, LimitKind, LimitScope, LimitStatus, Summary,
+    /// };
+    ///
+    /// let result = AnalysisResult::new(vec![], Summary::default(), AnalysisScope::new())
+    ///     .with_checks(vec![LimitCheck {
+    ///         limit: LimitKind::MaxProdUnits,
+    ///         scope: LimitScope::Total,
+    ///         actual: Some(31.0.into()),
+    ///         threshold: Some(30.0.into()),
+    ///         warn: None,
+    ///         status: LimitStatus::Fail,
+    ///     }]);
+    /// assert_eq!(result.violations.len(), 1);
+    /// assert_eq!(result.summary.status, LimitStatus::Fail);
+    /// ```
+    pub fn with_checks(self, checks: Vec<LimitCheck>) -> Self {
+        let violations = checks.iter().filter_map(LimitCheck::violation).collect();
+        let mut result = self.with_violations(violations);
+        result.checks = checks;
+        result
+    }
+
+    /// Returns the violation of a limit over all counted changes
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Limit to look up
+    ///
+    /// # Returns
+    ///
+    /// Violation with [`LimitScope::Total`], if any
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::{AnalysisResult, AnalysisScope, LimitKind, Summary};
+    ///
+    /// let result = AnalysisResult::new(vec![], Summary::default(), AnalysisScope::new());
+    /// assert!(result.violation(LimitKind::MaxProdUnits).is_none());
+    /// ```
+    pub fn violation(&self, limit: LimitKind) -> Option<&LimitViolation> {
+        self.violations
+            .iter()
+            .find(|v| v.limit == limit && v.scope == LimitScope::Total)
+    }
+
     /// Returns only production changes
     ///
+    /// Suppressed changes are excluded.
+    ///
     /// # Returns
     ///
     /// Iterator over production changes
@@ -232,11 +684,13 @@ impl AnalysisResult {
     pub fn production_changes(&self) -> impl Iterator<Item = &Change> {
         self.changes
             .iter()
-            .filter(|c| c.classification.is_production())
+            .filter(|c| !c.is_suppressed() && c.classification.is_production())
     }
 
     /// Returns only test-related changes
     ///
+    /// Suppressed changes are excluded.
+    ///
     /// # Returns
     ///
     /// Iterator over test-related changes
@@ -252,6 +706,24 @@ impl AnalysisResult {
     pub fn test_changes(&self) -> impl Iterator<Item = &Change> {
         self.changes
             .iter()
-            .filter(|c| c.classification.is_test_related())
+            .filter(|c| !c.is_suppressed() && c.classification.is_test_related())
+    }
+
+    /// Returns changes excluded by suppression markers
+    ///
+    /// # Returns
+    ///
+    /// Iterator over suppressed changes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::{AnalysisResult, AnalysisScope, Summary};
+    ///
+    /// let result = AnalysisResult::new(vec![], Summary::default(), AnalysisScope::new());
+    /// assert_eq!(result.suppressed_changes().count(), 0);
+    /// ```
+    pub fn suppressed_changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|c| c.is_suppressed())
     }
 }
@@ -4,7 +4,7 @@
 use serde::{Deserialize, Serialize};
 
 /// Classification of code type
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum CodeType {
     /// Production code
     Production,
@@ -18,9 +18,32 @@ pub enum CodeType {
     Example,
     /// Build script code
     BuildScript,
+    /// Machine-generated code (marked by a generated-file header, a
+    /// configured path pattern, or an `OUT_DIR` include)
+    Generated,
+    /// Fuzz targets (`fuzz/` directories and `fuzz_target!` invocations)
+    Fuzz,
+    /// Documentation-only modules and files, e.g. a module of `//!` docs
+    Documentation,
+    /// Third-party sources copied into the repository
+    Vendored,
 }
 
 impl CodeType {
+    /// Every code type, in declaration order
+    pub const ALL: [Self; 10] = [
+        Self::Production,
+        Self::Test,
+        Self::TestUtility,
+        Self::Benchmark,
+        Self::Example,
+        Self::BuildScript,
+        Self::Generated,
+        Self::Fuzz,
+        Self::Documentation,
+        Self::Vendored,
+    ];
+
     /// Returns string representation of code type
     ///
     /// # Returns
@@ -43,6 +66,72 @@ impl CodeType {
             Self::Benchmark => "benchmark",
             Self::Example => "example",
             Self::BuildScript => "build_script",
+            Self::Generated => "generated",
+            Self::Fuzz => "fuzz",
+            Self::Documentation => "documentation",
+            Self::Vendored => "vendored",
+        }
+    }
+
+    /// Returns the prefix used for per-type GitHub Actions outputs
+    ///
+    /// Prefixes are distinct from the aggregated `prod_*` and `test_*`
+    /// outputs, e.g. `tests_units_changed` counts only `Test` units while
+    /// `test_units_changed` covers all non-production code.
+    ///
+    /// # Returns
+    ///
+    /// Output name prefix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::CodeType;
+    ///
+    /// assert_eq!(CodeType::Benchmark.output_key(), "bench");
+    /// assert_eq!(CodeType::Test.output_key(), "tests");
+    /// ```
+    pub fn output_key(&self) -> &'static str {
+        match self {
+            Self::Production => "prod",
+            Self::Test => "tests",
+            Self::TestUtility => "test_utility",
+            Self::Benchmark => "bench",
+            Self::Example => "example",
+            Self::BuildScript => "build_script",
+            Self::Generated => "generated",
+            Self::Fuzz => "fuzz",
+            Self::Documentation => "documentation",
+            Self::Vendored => "vendored",
+        }
+    }
+
+    /// Returns a capitalized display label
+    ///
+    /// # Returns
+    ///
+    /// Label for headings and table rows
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::CodeType;
+    ///
+    /// assert_eq!(CodeType::BuildScript.label(), "Build script");
+    /// assert_eq!(CodeType::Fuzz.label(), "Fuzz");
+    /// ```
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Production => "Production",
+            Self::Test => "Test",
+            Self::TestUtility => "Test utility",
+            Self::Benchmark => "Benchmark",
+            Self::Example => "Example",
+            Self::BuildScript => "Build script",
+            Self::Generated => "Generated",
+            Self::Fuzz => "Fuzz",
+            Self::Documentation => "Documentation",
+            Self::Vendored => "Vendored",
         }
     }
 
@@ -83,4 +172,76 @@ impl CodeType {
     pub fn is_test_related(&self) -> bool {
         matches!(self, Self::Test | Self::TestUtility | Self::Benchmark)
     }
+
+    /// Checks if this is generated code
+    ///
+    /// # Returns
+    ///
+    /// `true` if code type is Generated
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::CodeType;
+    ///
+    /// assert!(CodeType::Generated.is_generated());
+    /// assert!(!CodeType::Production.is_generated());
+    /// ```
+    pub fn is_generated(&self) -> bool {
+        matches!(self, Self::Generated)
+    }
+}
+
+/// What decided the classification of a unit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum ClassificationReason {
+    /// A classification rule matched
+    Rule {
+        /// Rule identifier
+        rule: String,
+        /// Conditions that matched, e.g. ``path matches `tests/` ``
+        matched: String,
+    },
+    /// The file header marks the whole file as generated
+    GeneratedHeader {
+        /// Marker found in the header
+        marker: String,
+    },
+    /// No rule matched, so the unit is production code
+    Default,
+}
+
+impl ClassificationReason {
+    /// Returns a one-line description of the reason
+    ///
+    /// # Returns
+    ///
+    /// Human-readable description
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::ClassificationReason;
+    ///
+    /// let reason = ClassificationReason::Rule {
+    ///     rule: "test-attribute".to_string(),
+    ///     matched: "has attribute `test`".to_string(),
+    /// };
+    /// assert_eq!(
+    ///     reason.describe(),
+    ///     "rule test-attribute: has attribute `test`"
+    /// );
+    /// assert_eq!(
+    ///     ClassificationReason::Default.describe(),
+    ///     "no rule matched (default)"
+    /// );
+    /// ```
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Rule { rule, matched } => format!("rule {}: {}", rule, matched),
+            Self::GeneratedHeader { marker } => format!("generated file header `{}`", marker),
+            Self::Default => "no rule matched (default)".to_string(),
+        }
+    }
 }
@@ -0,0 +1,541 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use serde::{Deserialize, Serialize};
+
+use super::semantic_unit::SemanticUnitKind;
+
+/// Outcome of comparing a metric against its thresholds
+///
+/// Variants are ordered by severity, so the overall status of a run is the
+/// maximum over all checked limits.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitStatus {
+    /// Below every threshold
+    #[default]
+    Ok,
+    /// Past a warning threshold but within the failing limit
+    Warn,
+    /// Past the failing limit
+    Fail,
+}
+
+impl LimitStatus {
+    /// Returns status as a lowercase string
+    ///
+    /// # Returns
+    ///
+    /// `ok`, `warn` or `fail`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::LimitStatus;
+    ///
+    /// assert_eq!(LimitStatus::Warn.as_str(), "warn");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Warn => "warn",
+            Self::Fail => "fail",
+        }
+    }
+
+    /// Returns the icon shown for the status in markdown output
+    ///
+    /// # Returns
+    ///
+    /// Emoji icon
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::LimitStatus;
+    ///
+    /// assert_eq!(LimitStatus::Ok.icon(), "✅");
+    /// assert_eq!(LimitStatus::Fail.icon(), "❌");
+    /// ```
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Self::Ok => "✅",
+            Self::Warn => "⚠️",
+            Self::Fail => "❌",
+        }
+    }
+
+    /// Compares a value against an optional warning and failing maximum
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Measured value
+    /// * `warn` - Warning threshold
+    /// * `limit` - Failing limit
+    ///
+    /// # Returns
+    ///
+    /// `Fail` above the limit, `Warn` above the warning threshold, else `Ok`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::LimitStatus;
+    ///
+    /// assert_eq!(
+    ///     LimitStatus::of_max(25, Some(20), Some(30)),
+    ///     LimitStatus::Warn
+    /// );
+    /// assert_eq!(
+    ///     LimitStatus::of_max(31, Some(20), Some(30)),
+    ///     LimitStatus::Fail
+    /// );
+    /// assert_eq!(LimitStatus::of_max(31, None, None), LimitStatus::Ok);
+    /// ```
+    pub fn of_max(value: usize, warn: Option<usize>, limit: Option<usize>) -> Self {
+        if limit.is_some_and(|limit| value > limit) {
+            Self::Fail
+        } else if warn.is_some_and(|warn| value > warn) {
+            Self::Warn
+        } else {
+            Self::Ok
+        }
+    }
+}
+
+/// Limit a violation refers to, named after its `[limits]` key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitKind {
+    /// `max_prod_units`
+    MaxProdUnits,
+    /// `max_weighted_score`
+    MaxWeightedScore,
+    /// `max_prod_lines`
+    MaxProdLines,
+    /// An entry of `per_type`
+    PerType,
+    /// `min_test_ratio`
+    MinTestRatio,
+}
+
+impl LimitKind {
+    /// Returns the configuration key of the limit
+    ///
+    /// # Returns
+    ///
+    /// Key within `[limits]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::LimitKind;
+    ///
+    /// assert_eq!(LimitKind::MaxProdLines.as_str(), "max_prod_lines");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MaxProdUnits => "max_prod_units",
+            Self::MaxWeightedScore => "max_weighted_score",
+            Self::MaxProdLines => "max_prod_lines",
+            Self::PerType => "per_type",
+            Self::MinTestRatio => "min_test_ratio",
+        }
+    }
+
+    /// Checks whether the threshold is a minimum rather than a maximum
+    ///
+    /// # Returns
+    ///
+    /// `true` for `min_test_ratio`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::LimitKind;
+    ///
+    /// assert!(LimitKind::MinTestRatio.is_minimum());
+    /// assert!(!LimitKind::MaxProdUnits.is_minimum());
+    /// ```
+    pub fn is_minimum(&self) -> bool {
+        matches!(self, Self::MinTestRatio)
+    }
+}
+
+/// Part of the change set a limit is measured over
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitScope {
+    /// All counted production changes
+    Total,
+    /// Changed units of one kind
+    UnitKind(SemanticUnitKind),
+}
+
+/// Measured value or threshold of a limit
+///
+/// Counts are whole numbers and `min_test_ratio` values are fractions, so
+/// values are kept as `f64`. Equality compares with [`f64::total_cmp`],
+/// which makes it total and lets results containing limit values
+/// implement `Eq`. Serialized as a plain number.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct LimitValue(f64);
+
+impl LimitValue {
+    /// Creates a limit value
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Count or ratio
+    ///
+    /// # Returns
+    ///
+    /// A new LimitValue instance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::LimitValue;
+    ///
+    /// assert_eq!(LimitValue::new(0.25).get(), 0.25);
+    /// ```
+    pub fn new(value: f64) -> Self {
+        Self(value)
+    }
+
+    /// Returns the wrapped number
+    ///
+    /// # Returns
+    ///
+    /// Count or ratio as `f64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::LimitValue;
+    ///
+    /// assert_eq!(LimitValue::from(30.0).get(), 30.0);
+    /// ```
+    pub fn get(self) -> f64 {
+        self.0
+    }
+}
+
+impl From<f64> for LimitValue {
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+impl PartialEq for LimitValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_cmp(&other.0).is_eq()
+    }
+}
+
+impl Eq for LimitValue {}
+
+impl PartialEq<f64> for LimitValue {
+    fn eq(&self, other: &f64) -> bool {
+        self.0.total_cmp(other).is_eq()
+    }
+}
+
+/// A limit or warning threshold crossed by the analyzed changes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LimitViolation {
+    /// Violated limit
+    pub limit: LimitKind,
+    /// Measured value
+    pub actual: LimitValue,
+    /// Configured threshold that was crossed
+    pub threshold: LimitValue,
+    /// Changes the value was measured over
+    pub scope: LimitScope,
+    /// `Fail` for limits, `Warn` for warning thresholds
+    pub severity: LimitStatus,
+}
+
+impl LimitViolation {
+    /// Creates a new limit violation
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Violated limit
+    /// * `actual` - Measured value
+    /// * `threshold` - Crossed threshold
+    /// * `scope` - Changes the value was measured over
+    /// * `severity` - `Fail` or `Warn`
+    ///
+    /// # Returns
+    ///
+    /// A new LimitViolation instance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::{LimitKind, LimitScope, LimitStatus, LimitViolation};
+    ///
+    /// let violation = LimitViolation::new(
+    ///     LimitKind::MaxProdUnits,
+    ///     31.0,
+    ///     30.0,
+    ///     LimitScope::Total,
+    ///     LimitStatus::Fail,
+    /// );
+    /// assert_eq!(violation.severity, LimitStatus::Fail);
+    /// ```
+    pub fn new(
+        limit: LimitKind,
+        actual: f64,
+        threshold: f64,
+        scope: LimitScope,
+        severity: LimitStatus,
+    ) -> Self {
+        Self {
+            limit,
+            actual: actual.into(),
+            threshold: threshold.into(),
+            scope,
+            severity,
+        }
+    }
+
+    /// Formats a value of this violation's limit for display
+    ///
+    /// Ratios keep two decimals; counts are shown as integers.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Either `actual` or `threshold`
+    ///
+    /// # Returns
+    ///
+    /// Formatted value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::{LimitKind, LimitScope, LimitStatus, LimitViolation};
+    ///
+    /// let violation = LimitViolation::new(
+    ///     LimitKind::MinTestRatio,
+    ///     0.25,
+    ///     0.5,
+    ///     LimitScope::Total,
+    ///     LimitStatus::Fail,
+    /// );
+    /// assert_eq!(violation.format_value(violation.actual), "0.25");
+    /// ```
+    pub fn format_value(&self, value: LimitValue) -> String {
+        if self.limit.is_minimum() {
+            format!("{:.2}", value.get())
+        } else {
+            format!("{:.0}", value.get())
+        }
+    }
+
+    /// Returns a one-line plain text description of the violation
+    ///
+    /// # Returns
+    ///
+    /// The text of [`LimitViolation::describe`] without markdown
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::{LimitKind, LimitScope, LimitStatus, LimitViolation};
+    ///
+    /// let violation = LimitViolation::new(
+    ///     LimitKind::MaxProdUnits,
+    ///     31.0,
+    ///     30.0,
+    ///     LimitScope::Total,
+    ///     LimitStatus::Fail,
+    /// );
+    /// assert_eq!(violation.describe_text(), "31 units (limit: 30)");
+    /// ```
+    pub fn describe_text(&self) -> String {
+        self.render(false)
+    }
+
+    /// Returns a one-line markdown description of the violation
+    ///
+    /// # Returns
+    ///
+    /// Description such as `**31** units (limit: 30)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::{
+    ///     LimitKind, LimitScope, LimitStatus, LimitViolation, SemanticUnitKind,
+    /// };
+    ///
+    /// let violation = LimitViolation::new(
+    ///     LimitKind::PerType,
+    ///     3.0,
+    ///     2.0,
+    ///     LimitScope::UnitKind(SemanticUnitKind::Trait),
+    ///     LimitStatus::Warn,
+    /// );
+    /// assert_eq!(
+    ///     violation.describe(),
+    ///     "**3** changed units of type `trait` (warning at: 2)"
+    /// );
+    /// ```
+    pub fn describe(&self) -> String {
+        self.render(true)
+    }
+
+    fn render(&self, markdown: bool) -> String {
+        let metric = match (&self.limit, &self.scope) {
+            (LimitKind::PerType, LimitScope::UnitKind(kind)) if markdown => {
+                format!("changed units of type `{}`", kind.as_str())
+            }
+            (LimitKind::PerType, LimitScope::UnitKind(kind)) => {
+                format!("changed units of type {}", kind.as_str())
+            }
+            (LimitKind::MaxProdUnits | LimitKind::PerType, _) => "units".to_string(),
+            (LimitKind::MaxWeightedScore, _) => "weighted score".to_string(),
+            (LimitKind::MaxProdLines, _) => "lines added".to_string(),
+            (LimitKind::MinTestRatio, _) => "test lines per production line".to_string(),
+        };
+        let bound = match (self.severity, self.limit.is_minimum()) {
+            (LimitStatus::Warn, true) => "warning below",
+            (LimitStatus::Warn, false) => "warning at",
+            (_, true) => "minimum",
+            (_, false) => "limit",
+        };
+        let actual = self.format_value(self.actual);
+        format!(
+            "{} {} ({}: {})",
+            if markdown {
+                format!("**{}**", actual)
+            } else {
+                actual
+            },
+            metric,
+            bound,
+            self.format_value(self.threshold)
+        )
+    }
+}
+
+/// A configured limit measured against a change set
+///
+/// Unlike [`LimitViolation`], checks are produced for every configured
+/// limit, including the ones that pass.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LimitCheck {
+    /// Checked limit
+    pub limit: LimitKind,
+    /// Changes the value was measured over
+    pub scope: LimitScope,
+    /// Measured value, or `None` when the limit does not apply to the
+    /// change set (e.g. `min_test_ratio` below its production line floor)
+    pub actual: Option<LimitValue>,
+    /// Failing limit, if configured
+    pub threshold: Option<LimitValue>,
+    /// Warning threshold, if configured
+    pub warn: Option<LimitValue>,
+    /// Outcome of the check
+    pub status: LimitStatus,
+}
+
+impl LimitCheck {
+    /// Returns the limit key qualified by its scope
+    ///
+    /// # Returns
+    ///
+    /// Name such as `max_prod_units` or `per_type.trait`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::{
+    ///     LimitCheck, LimitKind, LimitScope, LimitStatus, SemanticUnitKind,
+    /// };
+    ///
+    /// let check = LimitCheck {
+    ///     limit: LimitKind::PerType,
+    ///     scope: LimitScope::UnitKind(SemanticUnitKind::Trait),
+    ///     actual: Some(1.0.into()),
+    ///     threshold: Some(2.0.into()),
+    ///     warn: None,
+    ///     status: LimitStatus::Ok,
+    /// };
+    /// assert_eq!(check.name(), "per_type.trait");
+    /// ```
+    pub fn name(&self) -> String {
+        match &self.scope {
+            LimitScope::Total => self.limit.as_str().to_string(),
+            LimitScope::UnitKind(kind) => format!("{}.{}", self.limit.as_str(), kind.as_str()),
+        }
+    }
+
+    /// Returns the violation the check reports, if any
+    ///
+    /// # Returns
+    ///
+    /// Violation of the crossed threshold, or `None` if the check passed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::{LimitCheck, LimitKind, LimitScope, LimitStatus};
+    ///
+    /// let check = LimitCheck {
+    ///     limit: LimitKind::MaxProdUnits,
+    ///     scope: LimitScope::Total,
+    ///     actual: Some(25.0.into()),
+    ///     threshold: Some(30.0.into()),
+    ///     warn: Some(20.0.into()),
+    ///     status: LimitStatus::Warn,
+    /// };
+    /// let violation = check.violation().unwrap();
+    /// assert_eq!(violation.threshold, 20.0);
+    /// ```
+    pub fn violation(&self) -> Option<LimitViolation> {
+        let threshold = match self.status {
+            LimitStatus::Ok => return None,
+            LimitStatus::Warn => self.warn?,
+            LimitStatus::Fail => self.threshold?,
+        };
+        Some(LimitViolation::new(
+            self.limit,
+            self.actual?.get(),
+            threshold.get(),
+            self.scope.clone(),
+            self.status,
+        ))
+    }
+}
+
+/// Returns the overall status of a set of violations
+///
+/// # Arguments
+///
+/// * `violations` - Violations found for a change set
+///
+/// # Returns
+///
+/// Highest severity, or `Ok` when there are none
+///
+/// # Examples
+///
+/// ```
+/// use rust_diff_analyzer::types::{LimitStatus, limit::overall_status};
+///
+/// assert_eq!(overall_status(&[]), LimitStatus::Ok);
+/// ```
+pub fn overall_status(violations: &[LimitViolation]) -> LimitStatus {
+    violations
+        .iter()
+        .map(|v| v.severity)
+        .max()
+        .unwrap_or_default()
+}
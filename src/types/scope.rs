@@ -12,8 +12,13 @@ pub enum ExclusionReason {
     NonRust,
     /// File matches an ignore pattern
     IgnorePattern(String),
+    /// File carries an excluding `.gitattributes` attribute, such as
+    /// `linguist-generated`, `linguist-vendored` or `rust-diff-analyzer=ignore`
+    GitAttribute(String),
     /// File was deleted in the diff
     Deleted,
+    /// File content is binary
+    Binary,
     /// File could not be read
     ReadError(String),
     /// File could not be parsed as Rust source
@@ -65,6 +70,9 @@ pub struct AnalysisScope {
     pub skipped_files: Vec<SkippedFile>,
     /// Patterns used for exclusion
     pub exclusion_patterns: Vec<String>,
+    /// Analyzed files recognized as generated code
+    #[serde(default)]
+    pub generated_files: Vec<PathBuf>,
 }
 
 impl AnalysisScope {
@@ -132,6 +140,27 @@ impl AnalysisScope {
         self.skipped_files.push(SkippedFile::new(path, reason));
     }
 
+    /// Records an analyzed file as generated code
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the generated file
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use rust_diff_analyzer::types::AnalysisScope;
+    ///
+    /// let mut scope = AnalysisScope::new();
+    /// scope.add_generated(PathBuf::from("src/proto/api.rs"));
+    /// assert_eq!(scope.generated_files.len(), 1);
+    /// ```
+    pub fn add_generated(&mut self, path: PathBuf) {
+        self.generated_files.push(path);
+    }
+
     /// Sets the exclusion patterns
     ///
     /// # Arguments
@@ -203,6 +232,33 @@ impl AnalysisScope {
             .count()
     }
 
+    /// Returns count of files skipped due to `.gitattributes`
+    ///
+    /// # Returns
+    ///
+    /// Number of files excluded by an attribute
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use rust_diff_analyzer::types::{AnalysisScope, ExclusionReason};
+    ///
+    /// let mut scope = AnalysisScope::new();
+    /// scope.add_skipped(
+    ///     PathBuf::from("vendor/dep.rs"),
+    ///     ExclusionReason::GitAttribute("linguist-vendored".to_string()),
+    /// );
+    /// assert_eq!(scope.attribute_count(), 1);
+    /// ```
+    pub fn attribute_count(&self) -> usize {
+        self.skipped_files
+            .iter()
+            .filter(|f| matches!(f.reason, ExclusionReason::GitAttribute(_)))
+            .count()
+    }
+
     /// Returns count of files skipped because they were deleted in the diff
     ///
     /// # Returns
@@ -229,6 +229,9 @@ pub struct SemanticUnit {
     pub span: LineSpan,
     /// Attributes on the unit (e.g., "test", "cfg(test)")
     pub attributes: Vec<String>,
+    /// Inline modules enclosing the unit within its file, outermost first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub module_path: Vec<String>,
 }
 
 impl SemanticUnit {
@@ -274,6 +277,7 @@ impl SemanticUnit {
             visibility,
             span,
             attributes,
+            module_path: Vec::new(),
         }
     }
 
@@ -322,9 +326,40 @@ impl SemanticUnit {
             visibility,
             span,
             attributes,
+            module_path: Vec::new(),
         }
     }
 
+    /// Sets the inline modules enclosing the unit
+    ///
+    /// # Arguments
+    ///
+    /// * `module_path` - Module names, outermost first
+    ///
+    /// # Returns
+    ///
+    /// The unit with the module path set
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::{LineSpan, SemanticUnit, SemanticUnitKind, Visibility};
+    ///
+    /// let unit = SemanticUnit::new(
+    ///     SemanticUnitKind::Function,
+    ///     "decode".to_string(),
+    ///     Visibility::Public,
+    ///     LineSpan::new(3, 5),
+    ///     vec![],
+    /// )
+    /// .with_module_path(vec!["wire".to_string()]);
+    /// assert_eq!(unit.module_path, ["wire"]);
+    /// ```
+    pub fn with_module_path(mut self, module_path: Vec<String>) -> Self {
+        self.module_path = module_path;
+        self
+    }
+
     /// Returns qualified name including impl context if present
     ///
     /// # Returns
@@ -362,6 +397,36 @@ impl SemanticUnit {
         }
     }
 
+    /// Returns the unit path within its file
+    ///
+    /// Joins the enclosing inline modules and the qualified name with `::`.
+    ///
+    /// # Returns
+    ///
+    /// Path such as `wire::Frame::decode`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::{LineSpan, SemanticUnit, SemanticUnitKind, Visibility};
+    ///
+    /// let unit = SemanticUnit::with_impl(
+    ///     SemanticUnitKind::Function,
+    ///     "decode".to_string(),
+    ///     "Frame".to_string(),
+    ///     Visibility::Public,
+    ///     LineSpan::new(3, 5),
+    ///     vec![],
+    /// )
+    /// .with_module_path(vec!["wire".to_string()]);
+    /// assert_eq!(unit.path_in_file(), "wire::Frame::decode");
+    /// ```
+    pub fn path_in_file(&self) -> String {
+        let mut segments = self.module_path.clone();
+        segments.push(self.qualified_name());
+        segments.join("::")
+    }
+
     /// Checks if unit has a specific attribute
     ///
     /// # Arguments
@@ -390,4 +455,34 @@ impl SemanticUnit {
     pub fn has_attribute(&self, attr: &str) -> bool {
         self.attributes.iter().any(|a| a == attr)
     }
+
+    /// Returns the features gating this unit via `#[cfg(feature = "...")]`
+    ///
+    /// Features are read from the `cfg_feature:<name>` markers recorded by
+    /// the AST visitor, including those inherited from enclosing modules and
+    /// impl blocks.
+    ///
+    /// # Returns
+    ///
+    /// Iterator over feature names
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::{LineSpan, SemanticUnit, SemanticUnitKind, Visibility};
+    ///
+    /// let unit = SemanticUnit::new(
+    ///     SemanticUnitKind::Function,
+    ///     "encode".to_string(),
+    ///     Visibility::Public,
+    ///     LineSpan::new(1, 5),
+    ///     vec!["cfg".to_string(), "cfg_feature:serde".to_string()],
+    /// );
+    /// assert_eq!(unit.cfg_features().collect::<Vec<_>>(), vec!["serde"]);
+    /// ```
+    pub fn cfg_features(&self) -> impl Iterator<Item = &str> {
+        self.attributes
+            .iter()
+            .filter_map(|attr| attr.strip_prefix("cfg_feature:"))
+    }
 }
@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use serde::{Deserialize, Serialize};
+
+/// Why a changed unit is excluded from counts and limits
+///
+/// Suppressed changes stay in the analysis result so reviewers still see
+/// them, but they do not count toward production or test totals.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum Suppression {
+    /// `#[cfg_attr(any(), rust_diff_analyzer::skip)]` on the unit or an
+    /// enclosing item
+    Attribute,
+    /// `// rust-diff-analyzer: ignore-next-item` above the unit or an
+    /// enclosing item
+    Comment,
+    /// `// rust-diff-analyzer: ignore-file` in the file's leading comments
+    File,
+    /// A `limits.allowlist` entry in the configuration
+    Allowlist {
+        /// Pattern that matched the unit
+        pattern: String,
+        /// Reason given for the entry
+        reason: String,
+    },
+}
+
+impl Suppression {
+    /// Returns a one-line description of the suppression
+    ///
+    /// # Returns
+    ///
+    /// Human-readable description naming the marker, or the allowlist
+    /// pattern and its reason
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_diff_analyzer::types::Suppression;
+    ///
+    /// assert_eq!(
+    ///     Suppression::File.describe(),
+    ///     "`// rust-diff-analyzer: ignore-file`"
+    /// );
+    ///
+    /// let allowlisted = Suppression::Allowlist {
+    ///     pattern: "crate::proto::*".to_string(),
+    ///     reason: "generated tables".to_string(),
+    /// };
+    /// assert_eq!(
+    ///     allowlisted.describe(),
+    ///     "allowlist `crate::proto::*`: generated tables"
+    /// );
+    /// ```
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Attribute => "`#[cfg_attr(any(), rust_diff_analyzer::skip)]`".to_string(),
+            Self::Comment => "`// rust-diff-analyzer: ignore-next-item`".to_string(),
+            Self::File => "`// rust-diff-analyzer: ignore-file`".to_string(),
+            Self::Allowlist { pattern, reason } => {
+                format!("allowlist `{}`: {}", pattern, reason)
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+pub mod build_script;
+pub mod manifest;
+
+use std::path::{Path, PathBuf};
+
+use masterror::AppError;
+
+use crate::{config::Config, git::GitAttributes, types::SkippedFile};
+
+/// Facts about the analyzed repository discovered from its files
+///
+/// Unlike [`Config`], which is written by users, a workspace is derived at
+/// runtime from the base directory (Cargo manifests and similar metadata)
+/// and supplements the configured classification settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Workspace {
+    /// Features only enabled from test contexts, derived from Cargo manifests
+    pub test_features: Vec<String>,
+    /// Rules from the repository's `.gitattributes`
+    pub attributes: GitAttributes,
+    /// Files reachable only from build scripts, derived from Cargo manifests
+    pub build_script_files: Vec<PathBuf>,
+    /// Directories of packages only used as build dependencies
+    pub build_dependency_dirs: Vec<PathBuf>,
+    /// Cargo manifests that could not be read or parsed
+    pub skipped_manifests: Vec<SkippedFile>,
+}
+
+impl Workspace {
+    /// Discovers repository facts below a base directory
+    ///
+    /// Detection steps disabled in the configuration are skipped. Cargo
+    /// manifests that cannot be read or parsed are recorded in
+    /// `skipped_manifests` instead of failing discovery.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_dir` - Base directory of the repository
+    /// * `config` - Configuration controlling which facts are discovered
+    ///
+    /// # Returns
+    ///
+    /// Discovered workspace or error
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `.gitattributes` exists but cannot be read
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::{Config, workspace::Workspace};
+    ///
+    /// let workspace = Workspace::discover(Path::new("does-not-exist"), &Config::default()).unwrap();
+    /// assert!(workspace.test_features.is_empty());
+    /// assert!(workspace.attributes.is_empty());
+    /// assert!(workspace.build_script_files.is_empty());
+    /// ```
+    pub fn discover(base_dir: &Path, config: &Config) -> Result<Self, AppError> {
+        let mut workspace = Self::default();
+
+        let classification = &config.classification;
+        if classification.detect_test_features || classification.detect_build_scripts {
+            let (manifests, skipped) = manifest::load_workspace_manifests(base_dir);
+            workspace.skipped_manifests = skipped;
+            if classification.detect_test_features {
+                workspace.test_features = manifest::test_only_features(&manifests);
+            }
+            if classification.detect_build_scripts {
+                workspace.build_script_files =
+                    build_script::build_script_files(base_dir, &manifests);
+                workspace.build_dependency_dirs =
+                    build_script::build_only_package_dirs(&manifests);
+            }
+        }
+
+        if config.classification.use_gitattributes {
+            workspace.attributes = GitAttributes::from_dir(base_dir)?;
+        }
+
+        Ok(workspace)
+    }
+}
@@ -0,0 +1,406 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use syn::{Attribute, Expr, ExprLit, Item, Lit, Meta};
+
+use super::manifest::{CargoManifest, DependencyKind};
+
+/// Collects the source files reachable only from build scripts
+///
+/// Each package's build script is followed through its `mod` declarations
+/// (honoring `#[path]`). Files that are also part of a library or binary
+/// module tree of the same package are shared code and left out.
+///
+/// # Arguments
+///
+/// * `base_dir` - Base directory of the repository
+/// * `manifests` - Manifests of every workspace package
+///
+/// # Returns
+///
+/// Sorted file paths relative to `base_dir`
+///
+/// # Examples
+///
+/// ```
+/// use std::{
+///     fs,
+///     path::{Path, PathBuf},
+/// };
+///
+/// use rust_diff_analyzer::workspace::{
+///     build_script::build_script_files, manifest::CargoManifest,
+/// };
+///
+/// let dir = tempfile::tempdir().unwrap();
+/// fs::create_dir_all(dir.path().join("tools")).unwrap();
+/// fs::write(
+///     dir.path().join("tools/gen.rs"),
+///     "mod tables;\nfn main() {}\n",
+/// )
+/// .unwrap();
+/// fs::write(dir.path().join("tools/tables.rs"), "pub fn emit() {}\n").unwrap();
+///
+/// let manifest = CargoManifest::parse(
+///     "[package]\nname = \"demo\"\nbuild = \"tools/gen.rs\"\n",
+///     Path::new(""),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     build_script_files(dir.path(), &[manifest]),
+///     [
+///         PathBuf::from("tools/gen.rs"),
+///         PathBuf::from("tools/tables.rs")
+///     ]
+/// );
+/// ```
+pub fn build_script_files(base_dir: &Path, manifests: &[CargoManifest]) -> Vec<PathBuf> {
+    let mut files = BTreeSet::new();
+
+    for manifest in manifests {
+        let Some(script) = &manifest.build_script else {
+            continue;
+        };
+        let script = normalize(script);
+        if !base_dir.join(&script).is_file() {
+            continue;
+        }
+
+        let mut build_tree = BTreeSet::new();
+        collect_module_tree(base_dir, &script, &mut build_tree);
+
+        let mut target_tree = BTreeSet::new();
+        for root in target_roots(base_dir, manifest) {
+            collect_module_tree(base_dir, &root, &mut target_tree);
+        }
+
+        files.extend(build_tree.difference(&target_tree).cloned());
+    }
+
+    files.into_iter().collect()
+}
+
+/// Returns the directories of packages only used as build dependencies
+///
+/// A workspace package is build-only when every workspace package depending
+/// on it does so through `[build-dependencies]`, or through `[dependencies]`
+/// of another build-only package. Packages nobody depends on, and those
+/// pulled in as regular or dev-dependencies, are never build-only.
+///
+/// # Arguments
+///
+/// * `manifests` - Manifests of every workspace package
+///
+/// # Returns
+///
+/// Sorted package directories relative to the base directory
+///
+/// # Examples
+///
+/// ```
+/// use std::path::{Path, PathBuf};
+///
+/// use rust_diff_analyzer::workspace::{
+///     build_script::build_only_package_dirs, manifest::CargoManifest,
+/// };
+///
+/// let app = CargoManifest::parse(
+///     "[package]\nname = \"app\"\n[build-dependencies]\ncodegen = { path = \"../codegen\" }\n",
+///     Path::new("crates/app"),
+/// )
+/// .unwrap();
+/// let codegen = CargoManifest::parse(
+///     "[package]\nname = \"codegen\"\n",
+///     Path::new("crates/codegen"),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     build_only_package_dirs(&[app, codegen]),
+///     [PathBuf::from("crates/codegen")]
+/// );
+/// ```
+pub fn build_only_package_dirs(manifests: &[CargoManifest]) -> Vec<PathBuf> {
+    let packages: BTreeMap<&str, &CargoManifest> = manifests
+        .iter()
+        .filter_map(|m| m.package.as_deref().map(|name| (name, m)))
+        .collect();
+
+    let mut build_only: BTreeSet<&str> = BTreeSet::new();
+    loop {
+        let mut changed = false;
+        for &name in packages.keys() {
+            if build_only.contains(name) {
+                continue;
+            }
+
+            let mut dependents = manifests.iter().flat_map(|m| {
+                let dependent = m.package.as_deref();
+                m.dependencies
+                    .iter()
+                    .filter(move |d| d.package == name && dependent != Some(name))
+                    .map(move |d| (dependent, d.kind))
+            });
+            let mut any = false;
+            let only_build = dependents.all(|(dependent, kind)| {
+                any = true;
+                match kind {
+                    DependencyKind::Build => true,
+                    DependencyKind::Normal => dependent.is_some_and(|d| build_only.contains(d)),
+                    DependencyKind::Dev => false,
+                }
+            });
+
+            if any && only_build {
+                build_only.insert(name);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    build_only
+        .iter()
+        .filter_map(|name| packages.get(name))
+        .map(|m| normalize(&m.dir))
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Crate roots of a package's library and binary targets
+fn target_roots(base_dir: &Path, manifest: &CargoManifest) -> Vec<PathBuf> {
+    let src = manifest.dir.join("src");
+    let mut roots: Vec<PathBuf> = manifest.target_paths.iter().map(|p| normalize(p)).collect();
+    roots.push(normalize(&src.join("lib.rs")));
+    roots.push(normalize(&src.join("main.rs")));
+
+    if let Ok(entries) = fs::read_dir(base_dir.join(&src).join("bin")) {
+        for entry in entries.flatten() {
+            let path = normalize(&src.join("bin").join(entry.file_name()));
+            if path.extension().is_some_and(|ext| ext == "rs") {
+                roots.push(path);
+            } else {
+                roots.push(path.join("main.rs"));
+            }
+        }
+    }
+
+    roots
+}
+
+/// Adds `root` and every file it declares through `mod` items to `files`
+///
+/// Missing, unreadable and unparsable files end the walk along that branch.
+fn collect_module_tree(base_dir: &Path, root: &Path, files: &mut BTreeSet<PathBuf>) {
+    let dir = root.parent().map(Path::to_path_buf).unwrap_or_default();
+    visit_file(base_dir, root, &dir, files);
+}
+
+fn visit_file(base_dir: &Path, file: &Path, module_dir: &Path, files: &mut BTreeSet<PathBuf>) {
+    let file = normalize(file);
+    if files.contains(&file) {
+        return;
+    }
+    let Ok(content) = fs::read_to_string(base_dir.join(&file)) else {
+        return;
+    };
+    let Ok(syntax) = syn::parse_file(&content) else {
+        return;
+    };
+    files.insert(file.clone());
+
+    let file_dir = file.parent().map(Path::to_path_buf).unwrap_or_default();
+    visit_items(base_dir, &syntax.items, &file_dir, module_dir, false, files);
+}
+
+fn visit_items(
+    base_dir: &Path,
+    items: &[Item],
+    file_dir: &Path,
+    module_dir: &Path,
+    inline: bool,
+    files: &mut BTreeSet<PathBuf>,
+) {
+    for item in items {
+        let Item::Mod(module) = item else {
+            continue;
+        };
+        let name = module.ident.to_string();
+        let path_attr = path_attribute(&module.attrs);
+
+        if let Some((_, content)) = &module.content {
+            let nested = match &path_attr {
+                Some(path) => module_dir.join(path),
+                None => module_dir.join(&name),
+            };
+            visit_items(base_dir, content, file_dir, &nested, true, files);
+            continue;
+        }
+
+        match path_attr {
+            Some(path) => {
+                let base = if inline { module_dir } else { file_dir };
+                let target = base.join(path);
+                let children = target.parent().map(Path::to_path_buf).unwrap_or_default();
+                visit_file(base_dir, &target, &children, files);
+            }
+            None => {
+                let flat = module_dir.join(format!("{}.rs", name));
+                if base_dir.join(&flat).is_file() {
+                    visit_file(base_dir, &flat, &module_dir.join(&name), files);
+                } else {
+                    let nested = module_dir.join(&name);
+                    visit_file(base_dir, &nested.join("mod.rs"), &nested, files);
+                }
+            }
+        }
+    }
+}
+
+fn path_attribute(attrs: &[Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| {
+        let Meta::NameValue(meta) = &attr.meta else {
+            return None;
+        };
+        if !meta.path.is_ident("path") {
+            return None;
+        }
+        match &meta.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(value),
+                ..
+            }) => Some(value.value()),
+            _ => None,
+        }
+    })
+}
+
+/// Resolves `.` and `..` components lexically
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            other => normalized.push(other.as_os_str()),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let full = root.join(path);
+        fs::create_dir_all(full.parent().expect("parent")).expect("mkdir");
+        fs::write(full, content).expect("write");
+    }
+
+    fn manifest(content: &str, dir: &str) -> CargoManifest {
+        CargoManifest::parse(content, Path::new(dir)).expect("manifest should parse")
+    }
+
+    #[test]
+    fn test_module_tree_follows_nested_and_path_modules() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let root = dir.path();
+        write(
+            root,
+            "build.rs",
+            "mod codegen;\n#[path = \"tools/schema.rs\"]\nmod schema;\nmod inline {\n    mod \
+             deep;\n}\nfn main() {}\n",
+        );
+        write(root, "codegen.rs", "mod emit;\n");
+        write(root, "codegen/emit.rs", "pub fn emit() {}\n");
+        write(root, "tools/schema.rs", "pub struct Schema;\n");
+        write(root, "inline/deep/mod.rs", "pub fn deep() {}\n");
+
+        let files = build_script_files(root, &[manifest("[package]\nname = \"a\"\n", "")]);
+        let files: Vec<_> = files.iter().map(|p| p.to_string_lossy()).collect();
+        assert_eq!(
+            files,
+            [
+                "build.rs",
+                "codegen/emit.rs",
+                "codegen.rs",
+                "inline/deep/mod.rs",
+                "tools/schema.rs",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_files_shared_with_library_are_excluded() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let root = dir.path();
+        write(
+            root,
+            "crates/core/build.rs",
+            "#[path = \"src/opcodes.rs\"]\nmod opcodes;\nmod helpers;\nfn main() {}\n",
+        );
+        write(root, "crates/core/helpers.rs", "pub fn helper() {}\n");
+        write(root, "crates/core/src/lib.rs", "mod opcodes;\n");
+        write(
+            root,
+            "crates/core/src/opcodes.rs",
+            "pub const NOP: u8 = 0;\n",
+        );
+
+        let files = build_script_files(
+            root,
+            &[manifest("[package]\nname = \"core\"\n", "crates/core")],
+        );
+        assert_eq!(
+            files,
+            [
+                PathBuf::from("crates/core/build.rs"),
+                PathBuf::from("crates/core/helpers.rs"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_only_packages_are_transitive() {
+        let app = manifest(
+            "[package]\nname = \"app\"\n[dependencies]\nshared = { path = \"../shared\" \
+             }\n[build-dependencies]\ncodegen = { path = \"../codegen\" }\n",
+            "crates/app",
+        );
+        let codegen = manifest(
+            "[package]\nname = \"codegen\"\n[dependencies]\nparser = { path = \"../parser\" }\n",
+            "crates/codegen",
+        );
+        let parser = manifest("[package]\nname = \"parser\"\n", "crates/parser");
+        let shared = manifest(
+            "[package]\nname = \"shared\"\n[dev-dependencies]\ncodegen = { path = \"../codegen\" \
+             }\n",
+            "crates/shared",
+        );
+
+        assert_eq!(
+            build_only_package_dirs(&[app.clone(), codegen.clone(), parser.clone()]),
+            [
+                PathBuf::from("crates/codegen"),
+                PathBuf::from("crates/parser")
+            ]
+        );
+        assert!(build_only_package_dirs(&[app, codegen, parser, shared]).is_empty());
+    }
+}
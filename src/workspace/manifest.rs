@@ -0,0 +1,729 @@
+// SPDX-FileCopyrightText: 2025 RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use masterror::AppError;
+use toml::{Table, Value};
+
+use crate::{
+    error::{FileReadError, ManifestError},
+    types::{ExclusionReason, SkippedFile},
+};
+
+/// Kind of dependency table a dependency was declared in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyKind {
+    /// `[dependencies]`
+    Normal,
+    /// `[dev-dependencies]`
+    Dev,
+    /// `[build-dependencies]`
+    Build,
+}
+
+/// A dependency declared in a Cargo manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dependency {
+    /// Key the dependency is declared under
+    pub name: String,
+    /// Name of the depended-on package (honors `package = "..."` renames)
+    pub package: String,
+    /// Table the dependency was declared in
+    pub kind: DependencyKind,
+    /// Features explicitly requested by the dependency declaration
+    pub features: Vec<String>,
+    /// Whether the dependency's default features are enabled
+    pub default_features: bool,
+}
+
+/// The parts of a `Cargo.toml` relevant to classification
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CargoManifest {
+    /// Directory containing the manifest, relative to the base directory
+    pub dir: PathBuf,
+    /// Package name, `None` for virtual workspace manifests
+    pub package: Option<String>,
+    /// Build script path relative to the base directory
+    ///
+    /// Taken from `package.build`, defaulting to `build.rs` next to the
+    /// manifest; `None` for virtual manifests and `build = false`.
+    pub build_script: Option<PathBuf>,
+    /// Crate roots declared by `[lib] path` and `[[bin]] path`, relative to
+    /// the base directory
+    pub target_paths: Vec<PathBuf>,
+    /// `[features]` table: feature name to the entries it enables
+    pub features: BTreeMap<String, Vec<String>>,
+    /// All declared dependencies, including target-specific ones
+    pub dependencies: Vec<Dependency>,
+    /// `[workspace.dependencies]` available for inheritance
+    pub workspace_dependencies: Vec<Dependency>,
+    /// `[workspace] members` patterns
+    pub workspace_members: Vec<String>,
+    /// `[workspace] exclude` patterns
+    pub workspace_exclude: Vec<String>,
+}
+
+impl CargoManifest {
+    /// Parses a manifest from its TOML content
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Manifest content
+    /// * `dir` - Directory containing the manifest, relative to the base directory
+    ///
+    /// # Returns
+    ///
+    /// Parsed manifest or error
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the content is not valid TOML
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::workspace::manifest::CargoManifest;
+    ///
+    /// let manifest = CargoManifest::parse(
+    ///     "[package]\nname = \"demo\"\n[features]\nmock = []\n",
+    ///     Path::new(""),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(manifest.package.as_deref(), Some("demo"));
+    /// assert!(manifest.features.contains_key("mock"));
+    /// ```
+    pub fn parse(content: &str, dir: &Path) -> Result<Self, AppError> {
+        let table: Table = toml::from_str(content).map_err(|e| {
+            AppError::from(ManifestError::new(dir.join("Cargo.toml"), e.to_string()))
+        })?;
+        Ok(Self::from_table(&table, dir, &[]))
+    }
+
+    /// Builds a manifest from its parsed table, resolving `workspace = true`
+    /// dependencies against the root's `[workspace.dependencies]`
+    fn from_table(table: &Table, dir: &Path, root_dependencies: &[Dependency]) -> Self {
+        let package = table
+            .get("package")
+            .and_then(|p| p.get("name"))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        let build_script = match table.get("package").and_then(|p| p.get("build")) {
+            Some(Value::String(path)) => Some(dir.join(path)),
+            Some(Value::Boolean(false)) => None,
+            _ => package.as_ref().map(|_| dir.join("build.rs")),
+        };
+
+        let mut target_paths: Vec<PathBuf> = table
+            .get("lib")
+            .and_then(|lib| lib.get("path"))
+            .and_then(Value::as_str)
+            .map(|path| dir.join(path))
+            .into_iter()
+            .collect();
+        if let Some(bins) = table.get("bin").and_then(Value::as_array) {
+            target_paths.extend(
+                bins.iter()
+                    .filter_map(|bin| bin.get("path").and_then(Value::as_str))
+                    .map(|path| dir.join(path)),
+            );
+        }
+
+        let features = table
+            .get("features")
+            .and_then(Value::as_table)
+            .map(|features| {
+                features
+                    .iter()
+                    .map(|(name, entries)| (name.clone(), string_array(Some(entries))))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let workspace = table.get("workspace");
+        let mut workspace_dependencies = root_dependencies.to_vec();
+        if let Some(deps) = workspace
+            .and_then(|w| w.get("dependencies"))
+            .and_then(Value::as_table)
+        {
+            for (name, spec) in deps {
+                workspace_dependencies.push(parse_dependency(
+                    name,
+                    spec,
+                    DependencyKind::Normal,
+                    &[],
+                ));
+            }
+        }
+
+        let mut dependencies = Vec::new();
+        collect_dependency_tables(table, &workspace_dependencies, &mut dependencies);
+        if let Some(targets) = table.get("target").and_then(Value::as_table) {
+            for target in targets.values().filter_map(Value::as_table) {
+                collect_dependency_tables(target, &workspace_dependencies, &mut dependencies);
+            }
+        }
+
+        Self {
+            dir: dir.to_path_buf(),
+            package,
+            build_script,
+            target_paths,
+            features,
+            dependencies,
+            workspace_dependencies,
+            workspace_members: string_array(workspace.and_then(|w| w.get("members"))),
+            workspace_exclude: string_array(workspace.and_then(|w| w.get("exclude"))),
+        }
+    }
+
+    /// Loads a manifest from `<base_dir>/<dir>/Cargo.toml`
+    ///
+    /// # Arguments
+    ///
+    /// * `base_dir` - Base directory of the repository
+    /// * `dir` - Directory containing the manifest, relative to `base_dir`
+    ///
+    /// # Returns
+    ///
+    /// Parsed manifest or error
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be read or parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    ///
+    /// use rust_diff_analyzer::workspace::manifest::CargoManifest;
+    ///
+    /// let manifest = CargoManifest::from_dir(Path::new("."), Path::new(""));
+    /// ```
+    pub fn from_dir(base_dir: &Path, dir: &Path) -> Result<Self, AppError> {
+        let path = base_dir.join(dir).join("Cargo.toml");
+        let content =
+            fs::read_to_string(&path).map_err(|e| AppError::from(FileReadError::new(&path, e)))?;
+        Self::parse(&content, dir)
+    }
+}
+
+fn string_array(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn collect_dependency_tables(table: &Table, inherited: &[Dependency], out: &mut Vec<Dependency>) {
+    let tables = [
+        ("dependencies", DependencyKind::Normal),
+        ("dev-dependencies", DependencyKind::Dev),
+        ("dev_dependencies", DependencyKind::Dev),
+        ("build-dependencies", DependencyKind::Build),
+        ("build_dependencies", DependencyKind::Build),
+    ];
+
+    for (key, kind) in tables {
+        let Some(deps) = table.get(key).and_then(Value::as_table) else {
+            continue;
+        };
+        for (name, spec) in deps {
+            out.push(parse_dependency(name, spec, kind, inherited));
+        }
+    }
+}
+
+fn parse_dependency(
+    name: &str,
+    spec: &Value,
+    kind: DependencyKind,
+    inherited: &[Dependency],
+) -> Dependency {
+    let mut dependency = Dependency {
+        name: name.to_string(),
+        package: name.to_string(),
+        kind,
+        features: Vec::new(),
+        default_features: true,
+    };
+
+    let Some(spec) = spec.as_table() else {
+        return dependency;
+    };
+
+    let uses_workspace = spec.get("workspace").and_then(Value::as_bool) == Some(true);
+    if uses_workspace && let Some(base) = inherited.iter().find(|d| d.name == name) {
+        dependency = Dependency {
+            kind,
+            ..base.clone()
+        };
+    }
+
+    if let Some(package) = spec.get("package").and_then(Value::as_str) {
+        dependency.package = package.to_string();
+    }
+    dependency
+        .features
+        .extend(string_array(spec.get("features")));
+    let default_features = spec
+        .get("default-features")
+        .or_else(|| spec.get("default_features"))
+        .and_then(Value::as_bool);
+    if let Some(enabled) = default_features {
+        dependency.default_features = enabled;
+    }
+
+    dependency
+}
+
+/// Loads the root manifest and every workspace member below `base_dir`
+///
+/// Member patterns support `*` wildcards within a path component, which
+/// covers the common `crates/*` layout. A missing root manifest yields an
+/// empty list. Manifests that cannot be read or parsed are skipped and
+/// returned with the reason, so one broken member does not stop the
+/// analysis; a broken root manifest skips its members as well.
+///
+/// # Arguments
+///
+/// * `base_dir` - Base directory of the repository
+///
+/// # Returns
+///
+/// All manifests loaded, root first, and the manifests that were skipped
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_diff_analyzer::workspace::manifest::load_workspace_manifests;
+///
+/// let (manifests, skipped) = load_workspace_manifests(Path::new("does-not-exist"));
+/// assert!(manifests.is_empty());
+/// assert!(skipped.is_empty());
+/// ```
+pub fn load_workspace_manifests(base_dir: &Path) -> (Vec<CargoManifest>, Vec<SkippedFile>) {
+    let mut skipped = Vec::new();
+    if !base_dir.join("Cargo.toml").is_file() {
+        return (Vec::new(), skipped);
+    }
+
+    let Some(root) = load_manifest(base_dir, Path::new(""), &[], &mut skipped) else {
+        return (Vec::new(), skipped);
+    };
+    let mut member_dirs = BTreeSet::new();
+    for pattern in &root.workspace_members {
+        member_dirs.extend(expand_member_pattern(base_dir, pattern));
+    }
+    for pattern in &root.workspace_exclude {
+        let excluded = expand_member_pattern(base_dir, pattern);
+        member_dirs.retain(|dir| !excluded.contains(dir));
+    }
+
+    let mut manifests = Vec::new();
+    for dir in member_dirs {
+        if dir.as_os_str().is_empty() || !base_dir.join(&dir).join("Cargo.toml").is_file() {
+            continue;
+        }
+        if let Some(member) =
+            load_manifest(base_dir, &dir, &root.workspace_dependencies, &mut skipped)
+        {
+            manifests.push(member);
+        }
+    }
+    manifests.insert(0, root);
+
+    (manifests, skipped)
+}
+
+/// Loads the manifest in `dir`, recording it in `skipped` on failure
+fn load_manifest(
+    base_dir: &Path,
+    dir: &Path,
+    root_dependencies: &[Dependency],
+    skipped: &mut Vec<SkippedFile>,
+) -> Option<CargoManifest> {
+    let path = dir.join("Cargo.toml");
+    let reason = match fs::read_to_string(base_dir.join(&path)) {
+        Ok(content) => match toml::from_str::<Table>(&content) {
+            Ok(table) => return Some(CargoManifest::from_table(&table, dir, root_dependencies)),
+            Err(e) => ExclusionReason::ParseError(e.message().to_string()),
+        },
+        Err(e) => ExclusionReason::ReadError(e.to_string()),
+    };
+    skipped.push(SkippedFile::new(path, reason));
+    None
+}
+
+fn expand_member_pattern(base_dir: &Path, pattern: &str) -> Vec<PathBuf> {
+    let mut candidates = vec![PathBuf::new()];
+
+    for component in pattern.split('/').filter(|c| !c.is_empty() && *c != ".") {
+        let mut next = Vec::new();
+        for candidate in &candidates {
+            if !component.contains('*') {
+                next.push(candidate.join(component));
+                continue;
+            }
+            let Ok(entries) = fs::read_dir(base_dir.join(candidate)) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if entry.path().is_dir() && wildcard_matches(component, &name) {
+                    next.push(candidate.join(name));
+                }
+            }
+        }
+        candidates = next;
+    }
+
+    candidates
+}
+
+fn wildcard_matches(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == text,
+        Some((prefix, rest)) => {
+            let Some(remaining) = text.strip_prefix(prefix) else {
+                return false;
+            };
+            (0..=remaining.len())
+                .filter(|i| remaining.is_char_boundary(*i))
+                .any(|i| wildcard_matches(rest, &remaining[i..]))
+        }
+    }
+}
+
+/// Derives features that are only ever enabled from test contexts
+///
+/// A feature is test-only when it is reachable from a `[dev-dependencies]`
+/// declaration on a workspace package, directly or through other features,
+/// but not from any package's `default` feature or from a `[dependencies]`
+/// or `[build-dependencies]` declaration. Features are reported by name;
+/// a name enabled for production in any package is never test-only.
+///
+/// # Arguments
+///
+/// * `manifests` - Manifests of every workspace package
+///
+/// # Returns
+///
+/// Sorted list of test-only feature names
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_diff_analyzer::workspace::manifest::{CargoManifest, test_only_features};
+///
+/// let manifest = CargoManifest::parse(
+///     r#"
+/// [package]
+/// name = "demo"
+///
+/// [features]
+/// default = ["std"]
+/// std = []
+/// mock = ["fake-clock"]
+/// fake-clock = []
+///
+/// [dev-dependencies]
+/// demo = { path = ".", features = ["mock"] }
+/// "#,
+///     Path::new(""),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(test_only_features(&[manifest]), vec!["fake-clock", "mock"]);
+/// ```
+pub fn test_only_features(manifests: &[CargoManifest]) -> Vec<String> {
+    let packages: BTreeMap<&str, &CargoManifest> = manifests
+        .iter()
+        .filter_map(|m| m.package.as_deref().map(|name| (name, m)))
+        .collect();
+
+    let mut prod_roots = Vec::new();
+    let mut test_roots = Vec::new();
+
+    for manifest in manifests {
+        if let Some(name) = manifest.package.as_deref() {
+            prod_roots.push((name.to_string(), "default".to_string()));
+        }
+        for dependency in &manifest.dependencies {
+            if !packages.contains_key(dependency.package.as_str()) {
+                continue;
+            }
+            let roots = match dependency.kind {
+                DependencyKind::Dev => &mut test_roots,
+                DependencyKind::Normal | DependencyKind::Build => &mut prod_roots,
+            };
+            if dependency.default_features {
+                roots.push((dependency.package.clone(), "default".to_string()));
+            }
+            for feature in &dependency.features {
+                roots.push((dependency.package.clone(), feature.clone()));
+            }
+        }
+    }
+
+    let prod = enabled_features(&packages, prod_roots);
+    let test = enabled_features(&packages, test_roots);
+
+    let prod_names: BTreeSet<&str> = prod.iter().map(|(_, f)| f.as_str()).collect();
+    let test_only: BTreeSet<String> = test
+        .iter()
+        .filter(|(_, feature)| feature != "default" && !prod_names.contains(feature.as_str()))
+        .map(|(_, feature)| feature.clone())
+        .collect();
+
+    test_only.into_iter().collect()
+}
+
+/// Computes the closure of `(package, feature)` pairs enabled by `roots`
+fn enabled_features(
+    packages: &BTreeMap<&str, &CargoManifest>,
+    roots: Vec<(String, String)>,
+) -> BTreeSet<(String, String)> {
+    let mut enabled = BTreeSet::new();
+    let mut queue = roots;
+
+    while let Some((package, feature)) = queue.pop() {
+        let Some(manifest) = packages.get(package.as_str()) else {
+            continue;
+        };
+        let Some(entries) = manifest.features.get(&feature) else {
+            continue;
+        };
+        if !enabled.insert((package.clone(), feature)) {
+            continue;
+        }
+
+        for entry in entries {
+            if entry.starts_with("dep:") {
+                continue;
+            }
+            match entry.split_once('/') {
+                Some((dependency, dep_feature)) => {
+                    let dependency = dependency.trim_end_matches('?');
+                    let target = manifest
+                        .dependencies
+                        .iter()
+                        .find(|d| d.name == dependency)
+                        .map(|d| d.package.clone())
+                        .unwrap_or_else(|| dependency.to_string());
+                    queue.push((target, dep_feature.to_string()));
+                }
+                None => queue.push((package.clone(), entry.clone())),
+            }
+        }
+    }
+
+    enabled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str, dir: &str) -> CargoManifest {
+        CargoManifest::parse(content, Path::new(dir)).expect("manifest should parse")
+    }
+
+    #[test]
+    fn test_parse_dependency_forms() {
+        let manifest = parse(
+            r#"
+[package]
+name = "app"
+
+[workspace.dependencies]
+core = { path = "crates/core", features = ["std"] }
+
+[dependencies]
+serde = "1"
+core = { workspace = true, features = ["extra"] }
+renamed = { package = "util", default-features = false }
+
+[target.'cfg(unix)'.dev-dependencies]
+core = { path = "crates/core", features = ["mock"] }
+"#,
+            "",
+        );
+
+        let core_normal = manifest
+            .dependencies
+            .iter()
+            .find(|d| d.package == "core" && d.kind == DependencyKind::Normal)
+            .expect("normal core dependency");
+        assert_eq!(core_normal.features, vec!["std", "extra"]);
+
+        let util = manifest
+            .dependencies
+            .iter()
+            .find(|d| d.package == "util")
+            .expect("renamed dependency");
+        assert!(!util.default_features);
+
+        assert!(
+            manifest
+                .dependencies
+                .iter()
+                .any(|d| d.package == "core" && d.kind == DependencyKind::Dev)
+        );
+    }
+
+    #[test]
+    fn test_cross_crate_test_features() {
+        let core = parse(
+            r#"
+[package]
+name = "core"
+
+[features]
+default = []
+mock = []
+serde = []
+"#,
+            "crates/core",
+        );
+        let app = parse(
+            r#"
+[package]
+name = "app"
+
+[dependencies]
+core = { path = "../core", features = ["serde"] }
+
+[dev-dependencies]
+core = { path = "../core", features = ["mock", "serde"] }
+"#,
+            "crates/app",
+        );
+
+        assert_eq!(test_only_features(&[core, app]), vec!["mock"]);
+    }
+
+    #[test]
+    fn test_feature_enabled_by_default_is_not_test_only() {
+        let manifest = parse(
+            r#"
+[package]
+name = "lib"
+
+[features]
+default = ["fixtures"]
+fixtures = []
+
+[dev-dependencies]
+lib = { path = ".", features = ["fixtures"] }
+"#,
+            "",
+        );
+
+        assert!(test_only_features(&[manifest]).is_empty());
+    }
+
+    #[test]
+    fn test_build_script_setting() {
+        let default = parse("[package]\nname = \"a\"\n", "crates/a");
+        assert_eq!(
+            default.build_script,
+            Some(PathBuf::from("crates/a/build.rs"))
+        );
+
+        let custom = parse(
+            "[package]\nname = \"b\"\nbuild = \"tools/gen.rs\"\n[lib]\npath = \"lib.rs\"\n",
+            "",
+        );
+        assert_eq!(custom.build_script, Some(PathBuf::from("tools/gen.rs")));
+        assert_eq!(custom.target_paths, vec![PathBuf::from("lib.rs")]);
+
+        let disabled = parse("[package]\nname = \"c\"\nbuild = false\n", "");
+        assert_eq!(disabled.build_script, None);
+
+        let virtual_manifest = parse("[workspace]\nmembers = []\n", "");
+        assert_eq!(virtual_manifest.build_script, None);
+    }
+
+    #[test]
+    fn test_invalid_manifest_is_error() {
+        assert!(CargoManifest::parse("[package", Path::new("")).is_err());
+    }
+
+    #[test]
+    fn test_wildcard_matches() {
+        assert!(wildcard_matches("*", "core"));
+        assert!(wildcard_matches("crate-*", "crate-core"));
+        assert!(!wildcard_matches("crate-*", "core"));
+        assert!(wildcard_matches("a*c", "abbc"));
+    }
+
+    #[test]
+    fn test_load_workspace_members() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let root = dir.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\nexclude = [\"crates/skip\"]\n",
+        )
+        .expect("write root");
+        for name in ["core", "skip"] {
+            fs::create_dir_all(root.join("crates").join(name)).expect("mkdir");
+            fs::write(
+                root.join("crates").join(name).join("Cargo.toml"),
+                format!("[package]\nname = \"{}\"\n", name),
+            )
+            .expect("write member");
+        }
+
+        let (manifests, skipped) = load_workspace_manifests(root);
+        let names: Vec<_> = manifests.iter().filter_map(|m| m.package.clone()).collect();
+        assert_eq!(names, vec!["core"]);
+        assert_eq!(manifests[1].dir, PathBuf::from("crates/core"));
+        assert!(skipped.is_empty());
+    }
+
+    #[test]
+    fn test_malformed_member_is_skipped() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let root = dir.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )
+        .expect("write root");
+        for (name, manifest) in [
+            ("broken", "[package\n"),
+            ("core", "[package]\nname = \"core\"\n"),
+        ] {
+            fs::create_dir_all(root.join("crates").join(name)).expect("mkdir");
+            fs::write(root.join("crates").join(name).join("Cargo.toml"), manifest)
+                .expect("write member");
+        }
+
+        let (manifests, skipped) = load_workspace_manifests(root);
+        let names: Vec<_> = manifests.iter().filter_map(|m| m.package.clone()).collect();
+        assert_eq!(names, vec!["core"]);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].path, PathBuf::from("crates/broken/Cargo.toml"));
+        assert!(matches!(skipped[0].reason, ExclusionReason::ParseError(_)));
+    }
+}
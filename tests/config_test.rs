@@ -130,4 +130,140 @@ mod config_tests {
 
         assert!(config.should_ignore_author("dependabot[bot]"));
     }
+
+    #[test]
+    fn test_glob_ignore_paths_with_negation() {
+        use std::path::Path;
+
+        let mut config = Config::default();
+        config.classification.ignore_paths = vec![
+            "crates/*/fixtures/**".to_string(),
+            "!crates/core/fixtures/keep.rs".to_string(),
+        ];
+
+        assert_eq!(
+            config.matched_ignore_pattern(Path::new("crates/cli/fixtures/a/b.rs")),
+            Some("crates/*/fixtures/**")
+        );
+        assert!(!config.should_ignore(Path::new("crates/core/fixtures/keep.rs")));
+        assert!(!config.should_ignore(Path::new("fixtures/a.rs")));
+    }
+
+    #[test]
+    fn test_glob_test_paths() {
+        use std::path::Path;
+
+        let mut config = Config::default();
+        config
+            .classification
+            .test_paths
+            .push("**/*_test.rs".to_string());
+
+        assert!(config.is_test_path(Path::new("src/parser/lexer_test.rs")));
+        assert!(config.is_test_path(Path::new("tests/it.rs")));
+        assert!(!config.is_test_path(Path::new("src/parser/lexer.rs")));
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_path_pattern() {
+        let mut config = Config::default();
+        config.classification.ignore_paths.push("!".to_string());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_naming_conventions_from_toml() {
+        use rust_diff_analyzer::types::CodeType;
+
+        let config: Config = toml::from_str(
+            r#"
+            [classification]
+            naming_conventions = [
+                { pattern = "**/*_spec.rs", code_type = "Test" },
+                { pattern = "fakes/", code_type = "TestUtility" },
+            ]
+            "#,
+        )
+        .expect("valid config");
+
+        assert_eq!(config.classification.naming_conventions.len(), 2);
+        assert_eq!(
+            config.classification.naming_conventions[1].code_type,
+            CodeType::TestUtility
+        );
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_non_test_naming_convention() {
+        use rust_diff_analyzer::{config::NamingConvention, types::CodeType};
+
+        let mut config = Config::default();
+        config
+            .classification
+            .naming_conventions
+            .push(NamingConvention::new("src/", CodeType::Production));
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_allowlist_from_toml_requires_reason() {
+        let config: Config = toml::from_str(
+            r#"
+            [[limits.allowlist]]
+            pattern = "crate::proto::*"
+            reason = "generated protocol tables"
+
+            [[limits.allowlist]]
+            pattern = "Config::default"
+            reason = ""
+            "#,
+        )
+        .expect("valid config");
+
+        assert_eq!(config.limits.allowlist[0].pattern, "crate::proto::*");
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_min_test_ratio_from_toml() {
+        let mut config: Config = toml::from_str(
+            r#"
+            [limits]
+            min_test_ratio = 0.5
+            min_test_ratio_above_prod_lines = 40
+            "#,
+        )
+        .expect("valid config");
+
+        assert_eq!(config.limits.min_test_ratio, Some(0.5));
+        assert_eq!(config.limits.min_test_ratio_above_prod_lines, 40);
+        assert!(config.validate().is_ok());
+
+        config.limits.min_test_ratio = Some(-1.0);
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_warn_thresholds_from_toml() {
+        let mut config: Config = toml::from_str(
+            r#"
+            [limits]
+            max_prod_units = 30
+
+            [limits.warn]
+            max_prod_units = 20
+
+            [limits.warn.per_type]
+            traits = 1
+            "#,
+        )
+        .expect("valid config");
+
+        assert_eq!(config.limits.warn.max_prod_units, Some(20));
+        assert!(config.validate().is_ok());
+
+        config.limits.warn.max_prod_units = Some(40);
+        assert!(config.validate().is_err());
+    }
 }
@@ -9,7 +9,9 @@ use rust_diff_analyzer::{
     config::Config,
     git::parse_diff,
     output::format_output,
-    types::{AnalysisResult, AnalysisScope, CodeType, SemanticUnitKind, Summary},
+    source::{MemorySource, Revision},
+    types::{AnalysisResult, AnalysisScope, CodeType, SemanticUnitKind, Summary, Suppression},
+    workspace::Workspace,
 };
 
 #[test]
@@ -43,8 +45,8 @@ pub fn existing() {
     assert_eq!(diffs.len(), 1);
     assert_eq!(diffs[0].total_added(), 4);
 
-    let result =
-        map_changes(&diffs, &config, |_| Ok(source.to_string())).expect("map_changes failed");
+    let source = MemorySource::new().with_file(Revision::New, diffs[0].path.clone(), source);
+    let result = map_changes(&diffs, &config, &source).expect("map_changes failed");
 
     assert!(!result.changes.is_empty());
 
@@ -128,6 +130,173 @@ fn test_path_based_classification() {
     assert_eq!(classification, CodeType::BuildScript);
 }
 
+#[test]
+fn test_naming_convention_classification() {
+    let code = "pub fn helper() {}\n#[test]\nfn checks() {}";
+    let units =
+        extract_semantic_units_from_str(code, Path::new("src/x.rs")).expect("extraction failed");
+    let helper = &units[0];
+    let test_fn = &units[1];
+
+    let mut config = Config::default();
+    assert_eq!(
+        classify_unit(helper, Path::new("src/parser_test.rs"), &config),
+        CodeType::Test
+    );
+    assert_eq!(
+        classify_unit(helper, Path::new("src/testing/fixtures.rs"), &config),
+        CodeType::TestUtility
+    );
+    assert_eq!(
+        classify_unit(test_fn, Path::new("src/mocks/db.rs"), &config),
+        CodeType::Test
+    );
+    assert_eq!(
+        classify_unit(helper, Path::new("src/testing_ground.rs"), &config),
+        CodeType::Production
+    );
+
+    // Changing the conventions after classifying must take effect
+    config.classification.naming_conventions =
+        vec![rust_diff_analyzer::config::NamingConvention::new(
+            "**/*_spec.rs",
+            CodeType::Test,
+        )];
+    assert_eq!(
+        classify_unit(helper, Path::new("src/parser_test.rs"), &config),
+        CodeType::Production
+    );
+    assert_eq!(
+        classify_unit(helper, Path::new("src/parser_spec.rs"), &config),
+        CodeType::Test
+    );
+}
+
+#[test]
+fn test_custom_rules_replace_default_cascade() {
+    let code = "pub fn render() {}\n#[test]\nfn renders() {}";
+    let units = extract_semantic_units_from_str(code, Path::new("examples/demo.rs"))
+        .expect("extraction failed");
+
+    let config: Config = toml::from_str(
+        r#"
+        [[classification.rules]]
+        id = "test-attribute"
+        attribute = "test"
+        code_type = "Test"
+
+        [[classification.rules]]
+        id = "public-examples-are-production"
+        path = "examples/**"
+        visibility = "Public"
+        code_type = "Production"
+
+        [[classification.rules]]
+        path = "examples/**"
+        code_type = "Example"
+        "#,
+    )
+    .expect("valid config");
+
+    let path = Path::new("examples/demo.rs");
+    assert_eq!(
+        classify_unit(&units[0], path, &config),
+        CodeType::Production
+    );
+    assert_eq!(classify_unit(&units[1], path, &config), CodeType::Test);
+    assert_eq!(
+        classify_unit(&units[0], Path::new("build.rs"), &config),
+        CodeType::Production
+    );
+}
+
+#[test]
+fn test_inline_suppression_markers() {
+    let diff = r#"diff --git a/src/vm.rs b/src/vm.rs
+--- a/src/vm.rs
++++ b/src/vm.rs
+@@ -0,0 +1,9 @@
++// rust-diff-analyzer: ignore-next-item
++pub fn opcodes() {
++    1;
++}
++
++pub fn run() {}
++
++#[cfg_attr(any(), rust_diff_analyzer::skip)]
++pub struct Table;
+diff --git a/src/tables.rs b/src/tables.rs
+--- a/src/tables.rs
++++ b/src/tables.rs
+@@ -0,0 +1,2 @@
++// rust-diff-analyzer: ignore-file
++pub fn lookup() {}
+"#;
+
+    let vm = "// rust-diff-analyzer: ignore-next-item\npub fn opcodes() {\n    1;\n}\n\npub fn \
+              run() {}\n\n#[cfg_attr(any(), rust_diff_analyzer::skip)]\npub struct Table;\n";
+    let tables = "// rust-diff-analyzer: ignore-file\npub fn lookup() {}\n";
+
+    let diffs = parse_diff(diff).expect("diff parse failed");
+    let source = MemorySource::new()
+        .with_file(Revision::New, "src/vm.rs", vm)
+        .with_file(Revision::New, "src/tables.rs", tables);
+    let result = map_changes(&diffs, &Config::default(), &source).expect("map_changes failed");
+
+    let suppression = |name: &str| {
+        result
+            .changes
+            .iter()
+            .find(|c| c.unit.name == name)
+            .and_then(|c| c.suppression.clone())
+    };
+    assert_eq!(suppression("opcodes"), Some(Suppression::Comment));
+    assert_eq!(suppression("run"), None);
+    assert_eq!(suppression("Table"), Some(Suppression::Attribute));
+    assert_eq!(suppression("lookup"), Some(Suppression::File));
+}
+
+#[test]
+fn test_allowlisted_units_are_suppressed() {
+    let diff = r#"diff --git a/src/config.rs b/src/config.rs
+--- a/src/config.rs
++++ b/src/config.rs
+@@ -0,0 +1,7 @@
++impl Config {
++    pub fn default() -> Self {
++        Self
++    }
++
++    pub fn load() {}
++}
+"#;
+    let source = "impl Config {\n    pub fn default() -> Self {\n        Self\n    }\n\n    pub \
+                  fn load() {}\n}\n";
+
+    let config = rust_diff_analyzer::config::ConfigBuilder::new()
+        .add_allowlist_entry("Config::default", "mechanical field list")
+        .build();
+    let diffs = parse_diff(diff).expect("diff parse failed");
+    let source = MemorySource::new().with_file(Revision::New, diffs[0].path.clone(), source);
+    let result = map_changes(&diffs, &config, &source).expect("map_changes failed");
+
+    let suppression = |name: &str| {
+        result
+            .changes
+            .iter()
+            .find(|c| c.unit.name == name)
+            .and_then(|c| c.suppression.clone())
+    };
+    assert_eq!(
+        suppression("default"),
+        Some(Suppression::Allowlist {
+            pattern: "Config::default".to_string(),
+            reason: "mechanical field list".to_string(),
+        })
+    );
+    assert_eq!(suppression("load"), None);
+}
+
 #[test]
 fn test_multiple_files_diff() {
     let diff = r#"diff --git a/src/a.rs b/src/a.rs
@@ -171,6 +340,7 @@ fn test_output_formatting() {
             test_lines_removed: 20,
             weighted_score: 15,
             exceeds_limit: false,
+            ..Default::default()
         },
         AnalysisScope::new(),
     );
@@ -434,6 +604,7 @@ fn test_config_limits() {
             test_lines_removed: 50,
             weighted_score: 200,
             exceeds_limit: true,
+            ..Default::default()
         },
         AnalysisScope::new(),
     );
@@ -496,3 +667,46 @@ pub fn compute() -> i32 {
     assert!(compute.has_attribute("inline"));
     assert!(compute.has_attribute("must_use"));
 }
+
+#[test]
+fn test_build_script_module_tree_classification() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("tools")).expect("mkdir");
+    std::fs::create_dir_all(root.join("crates/codegen/src")).expect("mkdir");
+    std::fs::write(
+        root.join("Cargo.toml"),
+        "[package]\nname = \"app\"\nbuild = \"tools/gen.rs\"\n\n[workspace]\nmembers = \
+         [\"crates/*\"]\n\n[build-dependencies]\ncodegen = { path = \"crates/codegen\" }\n",
+    )
+    .expect("write manifest");
+    std::fs::write(root.join("tools/gen.rs"), "mod tables;\nfn main() {}\n").expect("write");
+    std::fs::write(root.join("tools/tables.rs"), "pub fn emit() {}\n").expect("write");
+    std::fs::write(
+        root.join("crates/codegen/Cargo.toml"),
+        "[package]\nname = \"codegen\"\n",
+    )
+    .expect("write manifest");
+
+    let mut config = Config::default();
+    config.workspace = Workspace::discover(root, &config).expect("discover");
+
+    let units = extract_semantic_units_from_str("pub fn emit() {}", Path::new("tools/tables.rs"))
+        .expect("parse");
+    for path in [
+        "tools/gen.rs",
+        "tools/tables.rs",
+        "crates/codegen/src/lib.rs",
+    ] {
+        assert_eq!(
+            classify_unit(&units[0], Path::new(path), &config),
+            CodeType::BuildScript,
+            "{}",
+            path
+        );
+    }
+    assert_eq!(
+        classify_unit(&units[0], Path::new("src/lib.rs"), &config),
+        CodeType::Production
+    );
+}